@@ -48,17 +48,16 @@ fn use_custom_keybinds(mut commands: Commands, bevy_input: Res<ButtonInput<KeyCo
         && bevy_input.just_pressed(KeyCode::KeyS)
     {
         info!("Using custom keybinds");
-        commands.insert_resource(PromptKeymap {
-            submit: Some(ReplKeybind {
-                code: CrosstermKeyCode::Char('Y'),
-                mods: KeyModifiers::CONTROL,
-            }),
-            clear: Some(ReplKeybind {
-                code: CrosstermKeyCode::Char('X'),
-                mods: KeyModifiers::CONTROL,
-            }),
-            ..default()
+        let mut keymap = PromptKeymap::default();
+        keymap.submit = Some(ReplKeybind {
+            code: CrosstermKeyCode::Char('Y'),
+            mods: KeyModifiers::CONTROL,
         });
+        keymap.clear = Some(ReplKeybind {
+            code: CrosstermKeyCode::Char('X'),
+            mods: KeyModifiers::CONTROL,
+        });
+        commands.insert_resource(keymap);
     }
 }
 
@@ -3,52 +3,59 @@
 //! Demonstrates:
 //! - Using a REPL command derived with clap
 //! - Accessing a `Query` inside the observer function
-//! - Listing entities and optionally filtering by `Name`
+//! - Listing entities and optionally filtering them with an `EntitySelector`,
+//!   the same name matcher the built-in commands use
+//!
+//! To see an entity's full component data, enable the `inspect` feature and
+//! run `inspect <id or name>`. The `query` feature adds a generic
+//! `query --with <Component> --show <Component>` command for reflected
+//! components.
 
 use bevy::prelude::*;
+use bevy_repl::command::{parse_entity_selector, EntitySelector};
 use bevy_repl::prelude::*;
 
-/// List entities, optionally filtering by a substring of their Name component.
+/// List entities, optionally filtering them by their Name component.
 #[derive(Debug, Clone, Event, Default)]
 struct ListCommand {
-    /// Optional substring to filter `Name` by
-    name_contains: Option<String>,
+    /// Optional name, name glob or entity ID to filter by
+    name: Option<EntitySelector>,
 }
 
 impl ReplCommand for ListCommand {
     fn clap_command() -> clap::Command {
         clap::Command::new("list")
-            .about("List entities, optionally filter by name substring")
+            .about("List entities, optionally filtered by name")
             .arg(
-                clap::Arg::new("name_contains")
+                clap::Arg::new("name")
                     .short('n')
-                    .long("name-contains")
+                    .long("name")
                     .num_args(1)
                     .required(false)
-                    .help("Optional substring to filter Name by"),
+                    .value_parser(parse_entity_selector)
+                    .help("Optional Name or glob such as 'A*' to filter by"),
             )
     }
 
     fn to_event(matches: &clap::ArgMatches) -> bevy_repl::command::ReplResult<Self> {
-        let name_contains = matches.get_one::<String>("name_contains").cloned();
-        Ok(ListCommand { name_contains })
+        let name = matches.get_one::<EntitySelector>("name").cloned();
+        Ok(ListCommand { name })
     }
 }
 
 /// Observer demonstrating a read-only ECS query inside the handler.
 fn on_list(trigger: On<ListCommand>, query: Query<(Entity, Option<&Name>)>) {
     let cmd = trigger.event();
-    let needle = cmd.name_contains.as_deref();
 
     repl_println!("Entities:");
     let mut count = 0usize;
     for (entity, name_opt) in query.iter() {
-        let name_str = name_opt.map(|n| n.as_str()).unwrap_or("<unnamed>");
-        if let Some(substr) = needle {
-            if !name_str.contains(substr) {
+        if let Some(selector) = &cmd.name {
+            if !selector.matches(entity, name_opt) {
                 continue;
             }
         }
+        let name_str = name_opt.map(|n| n.as_str()).unwrap_or("<unnamed>");
         repl_println!("  {:?}: {}", entity, name_str);
         count += 1;
     }
@@ -69,7 +76,7 @@ fn instructions() {
     repl_println!();
     repl_println!("Try typing a command:");
     repl_println!("  `list`                         - List all entities");
-    repl_println!("  `list -n Al`                   - List entities whose name contains 'Al'");
+    repl_println!("  `list -n 'A*'`                 - List entities whose name starts with 'A'");
     repl_println!("  `quit`                         - Close the app");
     repl_println!();
     repl_println!("Press CTRL+C to exit any time.");
@@ -4,8 +4,13 @@
 //! - Defining a command with clap's derive macros
 //! - Automatic `ReplCommand` via `#[derive(ReplCommand)]`
 //! - Spawning an entity from an observer using `Commands`
+//! - Parsing a `Vec3` argument with a Bevy-aware value parser
+//!
+//! To change the spawned entity afterwards, enable the `component` feature and
+//! try `component set <name> Transform.translation.x 5.0`.
 
 use bevy::prelude::*;
+use bevy_repl::command::parse_vec3;
 use bevy_repl::prelude::*;
 
 /// Spawn an entity with an optional `Name` component.
@@ -13,6 +18,8 @@ use bevy_repl::prelude::*;
 struct SpawnEntityCommand {
     /// Optional name to attach to the spawned entity
     name: Option<String>,
+    /// Optional position for the entity's `Transform`
+    position: Option<Vec3>,
 }
 
 impl ReplCommand for SpawnEntityCommand {
@@ -27,11 +34,21 @@ impl ReplCommand for SpawnEntityCommand {
                     .required(false)
                     .help("Optional name to attach to the spawned entity"),
             )
+            .arg(
+                clap::Arg::new("position")
+                    .short('p')
+                    .long("position")
+                    .num_args(1)
+                    .required(false)
+                    .value_parser(parse_vec3)
+                    .help("Optional position, e.g. 1,2,3"),
+            )
     }
 
     fn to_event(matches: &clap::ArgMatches) -> bevy_repl::command::ReplResult<Self> {
         let name = matches.get_one::<String>("name").cloned();
-        Ok(SpawnEntityCommand { name })
+        let position = matches.get_one::<Vec3>("position").copied();
+        Ok(SpawnEntityCommand { name, position })
     }
 }
 
@@ -44,6 +61,9 @@ fn on_spawn(trigger: On<SpawnEntityCommand>, mut commands: Commands) {
     if let Some(n) = &cmd.name {
         e.insert(Name::new(n.clone()));
     }
+    if let Some(position) = cmd.position {
+        e.insert(Transform::from_translation(position));
+    }
 
     let id = e.id();
     repl_println!("Spawned entity with id: {:?}", id);
@@ -56,6 +76,7 @@ fn instructions() {
     repl_println!("Try typing a command:");
     repl_println!("  `spawn`                  - Spawn an unnamed entity");
     repl_println!("  `spawn -n Alice`         - Spawn an entity named 'Alice'");
+    repl_println!("  `spawn -p 1,2,3`         - Spawn an entity at (1, 2, 3)");
     repl_println!("  `quit`                   - Close the app");
     repl_println!();
     repl_println!("Press CTRL+C to exit any time.");
@@ -9,6 +9,9 @@
 //!   time-scale            # prints current value
 //!   time-scale --set 2.0  # sets absolute value
 //!   time-scale --add -0.5 # adds delta to current value
+//!
+//! For resources that derive `Reflect`, the opt-in `resource` built-in does
+//! this without a hand-written command: `resource set TimeScale.0 2.0`.
 
 use bevy::prelude::*;
 use bevy_repl::prelude::*;
@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::asset::{
+    AssetPath, LoadState, ReflectAsset, UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle,
+};
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<AssetsCommand>();
+    app.add_observer(on_assets);
+    app.init_resource::<FailedAssetLoads>();
+    app.add_systems(
+        Last,
+        record_failed_loads.run_if(resource_exists::<Messages<UntypedAssetLoadFailedEvent>>),
+    );
+}
+
+#[derive(Event, Clone, Default)]
+enum AssetsCommand {
+    /// Count assets and handles per asset type.
+    #[default]
+    Summary,
+    /// List the assets of one type.
+    List(String),
+    /// Reload the assets loaded from a path.
+    Reload(String),
+    /// List assets that failed to load.
+    Failed,
+}
+
+impl crate::command::ReplCommand for AssetsCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{Arg, Command};
+
+        Command::new("assets")
+            .about("Inspect loaded assets and reload them")
+            .args_conflicts_with_subcommands(true)
+            .arg(
+                Arg::new("type")
+                    .help("Asset type to list, e.g. Image; all types are summarized if omitted"),
+            )
+            .subcommand(
+                Command::new("reload")
+                    .about("Reload the assets loaded from a path")
+                    .arg(
+                        Arg::new("path")
+                            .required(true)
+                            .help("Asset path, e.g. textures/player.png"),
+                    ),
+            )
+            .subcommand(Command::new("failed").about("List assets that failed to load"))
+            .after_help("Asset types are listed if registered with app.register_asset_reflect.")
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(match matches.subcommand() {
+            Some(("reload", m)) => {
+                Self::Reload(m.get_one::<String>("path").cloned().unwrap_or_default())
+            }
+            Some(("failed", _)) => Self::Failed,
+            _ => match matches.get_one::<String>("type") {
+                Some(asset_type) => Self::List(asset_type.clone()),
+                None => Self::Summary,
+            },
+        })
+    }
+}
+
+/// The latest load error for each path, as reported by the asset server.
+#[derive(Resource, Default)]
+struct FailedAssetLoads(Vec<UntypedAssetLoadFailedEvent>);
+
+fn record_failed_loads(
+    mut failed: MessageReader<UntypedAssetLoadFailedEvent>,
+    mut loads: ResMut<FailedAssetLoads>,
+) {
+    for event in failed.read() {
+        loads.0.retain(|load| load.path != event.path);
+        loads.0.push(event.clone());
+    }
+}
+
+fn on_assets(trigger: On<AssetsCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = run_assets(world, &cmd) {
+            repl_println!("assets: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn run_assets(world: &World, cmd: &AssetsCommand) -> Result<(), String> {
+    let server = world
+        .get_resource::<AssetServer>()
+        .ok_or("no asset server found, is AssetPlugin added?")?;
+    match cmd {
+        AssetsCommand::Summary => print_summary(world, server),
+        AssetsCommand::List(name) => list_assets(world, server, name)?,
+        AssetsCommand::Reload(path) => {
+            if server.get_handles_untyped(path.clone()).is_empty() {
+                return Err(format!("no asset is loaded from '{path}'"));
+            }
+            server.reload(path.clone());
+            repl_println!("reloading {path} (errors show up in 'assets failed')");
+        }
+        AssetsCommand::Failed => print_failed(world, server),
+    }
+    Ok(())
+}
+
+/// Asset types in the type registry, sorted by short name.
+fn asset_types(world: &World) -> Vec<(String, String, ReflectAsset)> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut types: Vec<_> = registry
+        .iter()
+        .filter_map(|registration| {
+            let reflect = registration.data::<ReflectAsset>()?.clone();
+            let paths = registration.type_info().type_path_table();
+            Some((
+                paths.short_path().to_string(),
+                paths.path().to_string(),
+                reflect,
+            ))
+        })
+        .collect();
+    types.sort_by(|a, b| a.0.cmp(&b.0));
+    types
+}
+
+fn print_summary(world: &World, server: &AssetServer) {
+    let rows: Vec<(String, String)> = asset_types(world)
+        .into_iter()
+        .filter(|(_, _, reflect)| !reflect.is_empty(world))
+        .map(|(name, _, reflect)| {
+            let mut count = 0;
+            let mut handles = 0;
+            let mut untracked = 0;
+            for id in reflect.ids(world) {
+                count += 1;
+                match handle_count(server, id) {
+                    Some(n) => handles += n,
+                    None => untracked += 1,
+                }
+            }
+            let mut detail = format!("{count} asset{}, ", if count == 1 { "" } else { "s" });
+            if untracked == count {
+                detail.push_str("? handles");
+            } else {
+                detail.push_str(&format_handles(handles));
+            }
+            if untracked > 0 {
+                detail.push_str(&format!(" ({untracked} untracked)"));
+            }
+            (name, detail)
+        })
+        .collect();
+    if rows.is_empty() {
+        repl_println!("no reflected assets (register them with app.register_asset_reflect)");
+        return;
+    }
+    let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    for (name, detail) in rows {
+        repl_println!("{name:width$}  {detail}");
+    }
+}
+
+fn list_assets(world: &World, server: &AssetServer, name: &str) -> Result<(), String> {
+    let (_, _, reflect) = asset_types(world)
+        .into_iter()
+        .find(|(short, full, _)| short.eq_ignore_ascii_case(name) || full == name)
+        .ok_or_else(|| format!("no reflected asset type named '{name}'"))?;
+
+    let mut rows = Vec::new();
+    let mut unnamed = 0;
+    for id in reflect.ids(world) {
+        let Some(path) = server.get_path(id) else {
+            unnamed += 1;
+            continue;
+        };
+        let state = match server.load_state(id) {
+            LoadState::NotLoaded => "not loaded",
+            LoadState::Loading => "loading",
+            LoadState::Loaded => "loaded",
+            LoadState::Failed(_) => "failed",
+        };
+        let handles =
+            handle_count(server, id).map_or_else(|| "? handles".to_string(), format_handles);
+        rows.push((path.to_string(), state, handles));
+    }
+    if rows.is_empty() && unnamed == 0 {
+        repl_println!("no {name} assets");
+        return Ok(());
+    }
+    rows.sort();
+    let width = rows.iter().map(|(path, ..)| path.len()).max().unwrap_or(0);
+    for (path, state, handles) in rows {
+        repl_println!("{path:width$}  {state:10}  {handles}");
+    }
+    if unnamed > 0 {
+        repl_println!(
+            "{unnamed} asset{} added at runtime without a path",
+            if unnamed == 1 { "" } else { "s" }
+        );
+    }
+    Ok(())
+}
+
+/// Strong handles to an asset the server knows about, or `None` for assets
+/// added straight to `Assets<A>`, whose handles the server doesn't track.
+fn handle_count(server: &AssetServer, id: UntypedAssetId) -> Option<usize> {
+    if !server.is_managed(id) {
+        return None;
+    }
+    match server.get_id_handle_untyped(id) {
+        // Minus the handle we just made
+        Some(UntypedHandle::Strong(handle)) => Some(Arc::strong_count(&handle) - 1),
+        _ => Some(0),
+    }
+}
+
+fn format_handles(handles: usize) -> String {
+    format!("{handles} handle{}", if handles == 1 { "" } else { "s" })
+}
+
+fn print_failed(world: &World, server: &AssetServer) {
+    // Skip paths that loaded fine since, e.g. after a reload
+    let failed: Vec<(&AssetPath, String)> = world
+        .resource::<FailedAssetLoads>()
+        .0
+        .iter()
+        .filter(|load| {
+            !server
+                .get_path_id(load.path.clone())
+                .is_some_and(|id| server.is_loaded(id))
+        })
+        .map(|load| (&load.path, load.error.to_string()))
+        .collect();
+    if failed.is_empty() {
+        repl_println!("no failed asset loads");
+        return;
+    }
+    for (path, error) in failed {
+        repl_println!("{path}: {error}");
+    }
+}
+
+#[cfg(test)]
+mod assets_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+    use bevy::asset::AssetPlugin;
+
+    #[derive(Asset, Reflect)]
+    struct Note {
+        text: String,
+    }
+
+    fn setup(app: &mut App) {
+        app.init_asset::<Note>().register_asset_reflect::<Note>();
+    }
+
+    #[test]
+    fn test_assets_summary_and_list() {
+        let mut app = app((MinimalPlugins, AssetPlugin::default(), plugin, setup));
+        assert_eq!(
+            run_ok(&mut app, "assets"),
+            vec!["no reflected assets (register them with app.register_asset_reflect)"]
+        );
+        let _handle = app.world_mut().resource_mut::<Assets<Note>>().add(Note {
+            text: "hello".to_string(),
+        });
+        // The server doesn't track handles to assets added without it
+        assert_eq!(
+            run_ok(&mut app, "assets"),
+            vec!["Note  1 asset, ? handles (1 untracked)"]
+        );
+        assert_eq!(
+            run_ok(&mut app, "assets note"),
+            vec!["1 asset added at runtime without a path"]
+        );
+        assert_eq!(
+            run_ok(&mut app, "assets failed"),
+            vec!["no failed asset loads"]
+        );
+    }
+
+    #[test]
+    fn test_assets_errors() {
+        let mut app = app((MinimalPlugins, AssetPlugin::default(), plugin, setup));
+        for line in ["assets Sound", "assets reload missing.note"] {
+            let (outcome, _) = run(&mut app, line);
+            assert!(matches!(outcome, CommandOutcome::Failed(_)), "{line}");
+        }
+        let mut bare = app(plugin);
+        let (outcome, _) = run(&mut bare, "assets");
+        assert!(matches!(outcome, CommandOutcome::Failed(e) if e.contains("AssetPlugin")));
+    }
+}
@@ -0,0 +1,271 @@
+use crate::command::{parse_duration, ReplCommandTracker};
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::prelude::*;
+use bevy::time::TimeSystems;
+use std::time::Duration;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<ClockCommand>();
+    app.add_observer(on_clock);
+    app.init_resource::<PendingStep>();
+    app.add_systems(
+        First,
+        advance_paused_time
+            .after(TimeSystems)
+            .run_if(resource_exists::<Time<Virtual>>),
+    );
+}
+
+#[derive(Event, Clone, Default)]
+enum ClockCommand {
+    /// Print the real, virtual and fixed clocks.
+    #[default]
+    Status,
+    /// Pause virtual time.
+    Pause,
+    /// Resume virtual time.
+    Resume,
+    /// Set the relative speed of virtual time.
+    Speed(f32),
+    /// Advance virtual time (and with it the fixed schedule) while paused.
+    Step(Step),
+}
+
+/// Virtual time `clock step` advances by on the next frame.
+#[derive(Resource, Default)]
+struct PendingStep(Duration);
+
+/// How far `clock step` advances the fixed clock.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Step {
+    Ticks(u32),
+    Duration(Duration),
+}
+
+/// A bare integer is a number of ticks, anything else is a duration.
+fn parse_step(s: &str) -> Result<Step, String> {
+    match s.parse::<u32>() {
+        Ok(0) => Err("step must be at least one tick".to_string()),
+        Ok(ticks) => Ok(Step::Ticks(ticks)),
+        Err(_) => parse_duration(s).map(Step::Duration),
+    }
+}
+
+impl crate::command::ReplCommand for ClockCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{value_parser, Arg, Command};
+
+        Command::new("clock")
+            .about("Show and control Bevy's virtual and fixed clocks")
+            .subcommand(Command::new("status").about("Print the real, virtual and fixed clocks"))
+            .subcommand(Command::new("pause").about("Pause virtual time"))
+            .subcommand(Command::new("resume").about("Resume virtual time"))
+            .subcommand(
+                Command::new("speed")
+                    .about("Set the relative speed of virtual time")
+                    .arg(
+                        Arg::new("ratio")
+                            .required(true)
+                            .value_parser(value_parser!(f32))
+                            .help("e.g. 0.5 for half speed"),
+                    ),
+            )
+            .subcommand(
+                Command::new("step")
+                    .about("Advance virtual time and the fixed schedule while paused")
+                    .arg(
+                        Arg::new("amount").value_parser(parse_step).help(
+                            "Number of fixed ticks, or a duration such as 250ms [default: 1]",
+                        ),
+                    ),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(match matches.subcommand() {
+            Some(("pause", _)) => Self::Pause,
+            Some(("resume", _)) => Self::Resume,
+            Some(("speed", m)) => Self::Speed(m.get_one::<f32>("ratio").copied().unwrap_or(1.0)),
+            Some(("step", m)) => Self::Step(
+                m.get_one::<Step>("amount")
+                    .copied()
+                    .unwrap_or(Step::Ticks(1)),
+            ),
+            _ => Self::Status,
+        })
+    }
+}
+
+fn on_clock(trigger: On<ClockCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = run_clock(world, &cmd) {
+            repl_println!("clock: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn run_clock(world: &mut World, cmd: &ClockCommand) -> Result<(), String> {
+    if !world.contains_resource::<Time<Virtual>>() || !world.contains_resource::<Time<Fixed>>() {
+        return Err("no clocks found, is TimePlugin added?".to_string());
+    }
+    match *cmd {
+        ClockCommand::Status => print_status(world),
+        ClockCommand::Pause => {
+            world.resource_mut::<Time<Virtual>>().pause();
+            repl_println!("virtual time paused");
+        }
+        ClockCommand::Resume => {
+            world.resource_mut::<Time<Virtual>>().unpause();
+            repl_println!("virtual time resumed");
+        }
+        ClockCommand::Speed(ratio) => {
+            if !ratio.is_finite() || ratio < 0.0 {
+                return Err(format!(
+                    "speed must be a finite, non-negative number, got {ratio}"
+                ));
+            }
+            world
+                .resource_mut::<Time<Virtual>>()
+                .set_relative_speed(ratio);
+            repl_println!("virtual time speed {ratio}x");
+        }
+        ClockCommand::Step(step) => {
+            if !world.resource::<Time<Virtual>>().is_paused() {
+                return Err("pause the clock before stepping ('clock pause')".to_string());
+            }
+            let fixed = world.resource::<Time<Fixed>>();
+            let (timestep, overstep) = (fixed.timestep(), fixed.overstep());
+            let amount = match step {
+                Step::Ticks(ticks) => timestep * ticks,
+                Step::Duration(duration) => duration,
+            };
+            let mut pending = world.resource_mut::<PendingStep>();
+            // Steps that don't add up to a whole tick would change nothing
+            // in FixedUpdate, so ask for a longer one instead
+            let ticks = (overstep + pending.0 + amount).as_nanos() / timestep.as_nanos().max(1);
+            if ticks == 0 {
+                return Err(format!(
+                    "{amount:.3?} is shorter than one fixed tick ({timestep:.3?})"
+                ));
+            }
+            pending.0 += amount;
+            repl_println!(
+                "stepping {:.3?} ({ticks} fixed tick(s) of {timestep:.3?}) on the next update",
+                pending.0
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Apply a pending `clock step` to the paused virtual clock. Update systems
+/// see it as this frame's delta, and the fixed loop runs as many ticks as fit.
+fn advance_paused_time(
+    mut pending: ResMut<PendingStep>,
+    mut virt: ResMut<Time<Virtual>>,
+    mut time: ResMut<Time>,
+) {
+    let step = std::mem::take(&mut pending.0);
+    // Resumed since the step was queued, so it already has a regular delta
+    if step.is_zero() || !virt.is_paused() {
+        return;
+    }
+    virt.advance_by(step);
+    *time = virt.as_generic();
+}
+
+fn print_status(world: &World) {
+    let real = world.resource::<Time<Real>>();
+    let virt = world.resource::<Time<Virtual>>();
+    let fixed = world.resource::<Time<Fixed>>();
+    repl_println!(
+        "real     elapsed {:.3?}  delta {:.3?}",
+        real.elapsed(),
+        real.delta()
+    );
+    repl_println!(
+        "virtual  elapsed {:.3?}  delta {:.3?}  speed {}x{}",
+        virt.elapsed(),
+        virt.delta(),
+        virt.relative_speed(),
+        if virt.is_paused() { "  paused" } else { "" }
+    );
+    repl_println!(
+        "fixed    elapsed {:.3?}  delta {:.3?}  timestep {:.3?}  overstep {:.3?}",
+        fixed.elapsed(),
+        fixed.delta(),
+        fixed.timestep(),
+        fixed.overstep()
+    );
+}
+
+#[cfg(test)]
+mod clock_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+    use bevy::time::TimePlugin;
+
+    fn elapsed(app: &App) -> Duration {
+        app.world().resource::<Time<Virtual>>().elapsed()
+    }
+
+    #[test]
+    fn test_parse_step() {
+        assert_eq!(parse_step("3"), Ok(Step::Ticks(3)));
+        assert_eq!(
+            parse_step("250ms"),
+            Ok(Step::Duration(Duration::from_millis(250)))
+        );
+        assert!(parse_step("0").is_err());
+    }
+
+    #[test]
+    fn test_clock_pause_speed_resume() {
+        let mut app = app((TimePlugin, plugin));
+        run_ok(&mut app, "clock pause");
+        assert!(app.world().resource::<Time<Virtual>>().is_paused());
+        run_ok(&mut app, "clock speed 0.5");
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().relative_speed(),
+            0.5
+        );
+        let (outcome, _) = run(&mut app, "clock speed -1");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        run_ok(&mut app, "clock resume");
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+    }
+
+    #[test]
+    fn test_clock_step_advances_paused_time() {
+        let mut app = app((TimePlugin, plugin));
+        let (outcome, _) = run(&mut app, "clock step");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+
+        run_ok(&mut app, "clock pause");
+        app.update();
+        let before = elapsed(&app);
+        run_ok(&mut app, "clock step 250ms");
+        app.update();
+        assert_eq!(elapsed(&app) - before, Duration::from_millis(250));
+        assert_eq!(
+            app.world().resource::<Time>().delta(),
+            Duration::from_millis(250)
+        );
+        // Nothing is left over for the frame after
+        app.update();
+        assert_eq!(elapsed(&app) - before, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_clock_step_rejects_less_than_a_tick() {
+        let mut app = app((TimePlugin, plugin));
+        run_ok(&mut app, "clock pause");
+        let (outcome, _) = run(&mut app, "clock step 1ns");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        assert!(app.world().resource::<PendingStep>().0.is_zero());
+    }
+}
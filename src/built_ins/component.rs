@@ -0,0 +1,311 @@
+use crate::command::{parse_entity_selector, EntitySelector, ReplCommandTracker};
+use crate::prelude::*;
+use crate::reflection::{find_component, find_registration, parse_ron_for, parse_ron_or_default};
+use crate::repl_println;
+use bevy::prelude::*;
+use bevy::reflect::GetPath;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<ComponentCommand>();
+    app.add_observer(on_component);
+}
+
+#[derive(Event, Clone)]
+enum ComponentCommand {
+    /// Set a component field from RON.
+    Set {
+        entity: EntitySelector,
+        path: String,
+        value: String,
+    },
+    /// Insert (or replace) a component from RON or its default.
+    Insert {
+        entity: EntitySelector,
+        component: String,
+        value: Option<String>,
+    },
+    /// Remove a component.
+    Remove {
+        entity: EntitySelector,
+        component: String,
+    },
+}
+
+impl Default for ComponentCommand {
+    fn default() -> Self {
+        Self::Remove {
+            entity: EntitySelector::default(),
+            component: String::new(),
+        }
+    }
+}
+
+impl crate::command::ReplCommand for ComponentCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{Arg, Command};
+
+        let entity = || {
+            Arg::new("entity")
+                .required(true)
+                .value_parser(parse_entity_selector)
+                .help("Entity ID (e.g. 12v1) or Name")
+        };
+        let value = || {
+            Arg::new("value")
+                .num_args(1..)
+                .allow_hyphen_values(true)
+                .help("Value in RON, e.g. 5.0 or (current: 100)")
+        };
+        Command::new("component")
+            .about("Modify reflected components on an entity")
+            .subcommand_required(true)
+            .subcommand(
+                Command::new("set")
+                    .about("Set a component or one of its fields")
+                    .arg(entity())
+                    .arg(
+                        Arg::new("path")
+                            .required(true)
+                            .help("Component and field path, e.g. Transform.translation.x"),
+                    )
+                    .arg(value().required(true)),
+            )
+            .subcommand(
+                Command::new("insert")
+                    .about("Insert or replace a component")
+                    .arg(entity())
+                    .arg(Arg::new("component").required(true).help("e.g. Health"))
+                    .arg(value().help("Value in RON, or the component's default if omitted")),
+            )
+            .subcommand(
+                Command::new("remove")
+                    .about("Remove a component")
+                    .arg(entity())
+                    .arg(Arg::new("component").required(true).help("e.g. Frozen")),
+            )
+            .after_help("Values are parsed as RON. Quotes around strings are optional.")
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        let entity = |m: &clap::ArgMatches| {
+            m.get_one::<EntitySelector>("entity")
+                .cloned()
+                .unwrap_or_default()
+        };
+        let string =
+            |m: &clap::ArgMatches, id: &str| m.get_one::<String>(id).cloned().unwrap_or_default();
+        let value = |m: &clap::ArgMatches| {
+            m.get_many::<String>("value")
+                .map(|v| v.cloned().collect::<Vec<_>>().join(" "))
+        };
+        Ok(match matches.subcommand() {
+            Some(("set", m)) => Self::Set {
+                entity: entity(m),
+                path: string(m, "path"),
+                value: value(m).unwrap_or_default(),
+            },
+            Some(("insert", m)) => Self::Insert {
+                entity: entity(m),
+                component: string(m, "component"),
+                value: value(m),
+            },
+            Some(("remove", m)) => Self::Remove {
+                entity: entity(m),
+                component: string(m, "component"),
+            },
+            _ => Self::default(),
+        })
+    }
+}
+
+fn on_component(trigger: On<ComponentCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = run_component(world, &cmd) {
+            repl_println!("component: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn run_component(world: &mut World, cmd: &ComponentCommand) -> Result<(), String> {
+    let selector = match cmd {
+        ComponentCommand::Set { entity, .. }
+        | ComponentCommand::Insert { entity, .. }
+        | ComponentCommand::Remove { entity, .. } => entity,
+    };
+    let entities = selector.resolve(world);
+    if entities.is_empty() {
+        return Err(format!("no entity matches {selector}"));
+    }
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    match cmd {
+        ComponentCommand::Set { path, value, .. } => {
+            let (name, field_path) = match path.split_once('.') {
+                Some((name, field_path)) => (name, field_path),
+                None => (path.as_str(), ""),
+            };
+            let (id, reflect) = find_component(world, &registry, name)?;
+            if !world
+                .components()
+                .get_info(id)
+                .is_some_and(|info| info.mutable())
+            {
+                return Err(format!(
+                    "{name} is immutable, use 'component insert' to replace it"
+                ));
+            }
+            // Check every entity before changing any, so a bad match doesn't
+            // leave the selection half-updated
+            let mut values = Vec::with_capacity(entities.len());
+            for &entity in &entities {
+                let component = reflect
+                    .reflect(world.entity(entity))
+                    .ok_or_else(|| format!("{entity} has no {name} component"))?;
+                let target = if field_path.is_empty() {
+                    component.as_partial_reflect()
+                } else {
+                    component
+                        .reflect_path(field_path)
+                        .map_err(|e| format!("{entity}: {e}"))?
+                };
+                let parsed = parse_ron_for(&registry, target, value)
+                    .map_err(|e| format!("{entity}: {e}"))?;
+                values.push(parsed);
+            }
+            for (entity, parsed) in entities.into_iter().zip(values) {
+                let Some(mut component) = reflect.reflect_mut(world.entity_mut(entity)) else {
+                    continue;
+                };
+                let target = if field_path.is_empty() {
+                    component.as_partial_reflect_mut()
+                } else {
+                    component
+                        .reflect_path_mut(field_path)
+                        .map_err(|e| e.to_string())?
+                };
+                target
+                    .try_apply(parsed.as_ref())
+                    .map_err(|e| format!("{entity}: {e}"))?;
+                repl_println!("{entity}: {path} = {target:?}");
+            }
+        }
+        ComponentCommand::Insert {
+            component, value, ..
+        } => {
+            let (_, reflect) = find_component(world, &registry, component)?;
+            let registration = find_registration(&registry, component)?;
+            let value = parse_ron_or_default(&registry, registration, value.as_deref())?;
+            for entity in entities {
+                reflect.insert(&mut world.entity_mut(entity), value.as_ref(), &registry);
+                repl_println!("{entity}: inserted {component}");
+            }
+        }
+        ComponentCommand::Remove { component, .. } => {
+            let (_, reflect) = find_component(world, &registry, component)?;
+            if let Some(entity) = entities
+                .iter()
+                .find(|&&entity| !reflect.contains(world.entity(entity)))
+            {
+                return Err(format!("{entity} has no {component} component"));
+            }
+            for entity in entities {
+                reflect.remove(&mut world.entity_mut(entity));
+                repl_println!("{entity}: removed {component}");
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod component_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Health {
+        hp: f32,
+        max: f32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Frozen;
+
+    fn enemies_app() -> (App, [Entity; 3]) {
+        let mut app = app(plugin);
+        app.register_type::<Health>().register_type::<Frozen>();
+        let world = app.world_mut();
+        let health = || Health { hp: 5.0, max: 5.0 };
+        let entities = [
+            world.spawn((Name::new("Enemy1"), health())).id(),
+            world.spawn((Name::new("Enemy2"), health())).id(),
+            world.spawn(Name::new("Enemy3")).id(),
+        ];
+        (app, entities)
+    }
+
+    fn hp(app: &App, entity: Entity) -> Option<f32> {
+        app.world().get::<Health>(entity).map(|health| health.hp)
+    }
+
+    #[test]
+    fn test_component_set_over_selector() {
+        let (mut app, [a, b, c]) = enemies_app();
+        let (outcome, _) = run(&mut app, "component set Enemy? Health.hp 1.5");
+        // Enemy3 has no Health, so nothing is changed
+        assert!(matches!(outcome, CommandOutcome::Failed(e) if e.contains("no Health component")));
+        assert_eq!(hp(&app, a), Some(5.0));
+
+        run_ok(&mut app, "component insert Enemy3 Health");
+        run_ok(&mut app, "component set Enemy? Health.hp 1.5");
+        assert_eq!(hp(&app, a), Some(1.5));
+        assert_eq!(hp(&app, b), Some(1.5));
+        assert_eq!(hp(&app, c), Some(1.5));
+        assert_eq!(app.world().get::<Health>(a).unwrap().max, 5.0);
+    }
+
+    #[test]
+    fn test_component_set_bad_value_changes_nothing() {
+        let (mut app, [a, b, _]) = enemies_app();
+        let (outcome, _) = run(&mut app, "component set Enemy1 Health.hp abc");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        assert_eq!(hp(&app, a), Some(5.0));
+        let (outcome, _) = run(&mut app, "component set Enemy2 Health (hp: 2.0, max: 3.0)");
+        assert_eq!(outcome, CommandOutcome::Success);
+        assert_eq!(
+            app.world().get::<Health>(b),
+            Some(&Health { hp: 2.0, max: 3.0 })
+        );
+    }
+
+    #[test]
+    fn test_component_insert_and_remove() {
+        let (mut app, [a, b, c]) = enemies_app();
+        run_ok(
+            &mut app,
+            "component insert Enemy3 Health (hp: 1.0, max: 2.0)",
+        );
+        assert_eq!(hp(&app, c), Some(1.0));
+        run_ok(&mut app, "component insert Enemy* Frozen");
+        assert!([a, b, c]
+            .iter()
+            .all(|&e| app.world().get::<Frozen>(e).is_some()));
+        run_ok(&mut app, "component remove Enemy1 Frozen");
+        assert!(app.world().get::<Frozen>(a).is_none());
+        assert!(app.world().get::<Frozen>(b).is_some());
+    }
+
+    #[test]
+    fn test_component_remove_missing_changes_nothing() {
+        let (mut app, [a, ..]) = enemies_app();
+        let (outcome, _) = run(&mut app, "component remove Enemy* Health");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        assert_eq!(hp(&app, a), Some(5.0));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticsPlugin, DiagnosticsStore, EntityCountDiagnosticsPlugin,
+    FrameTimeDiagnosticsPlugin,
+};
+use bevy::prelude::*;
+
+/// Adds the `diag` command and whichever diagnostics plugins are still
+/// missing, so they are built and finished like any other plugin. Plugins that
+/// bring their own, such as `DefaultPlugins` with its `DiagnosticsPlugin`, must
+/// be added before the REPL, or Bevy panics on the duplicate.
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<DiagCommand>();
+    app.add_observer(on_diag);
+    if !app.is_plugin_added::<DiagnosticsPlugin>() {
+        app.add_plugins(DiagnosticsPlugin);
+    }
+    if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+        app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+    }
+    if !app.is_plugin_added::<EntityCountDiagnosticsPlugin>() {
+        app.add_plugins(EntityCountDiagnosticsPlugin::default());
+    }
+}
+
+#[derive(Event, Clone, Default)]
+struct DiagCommand {
+    filter: Option<String>,
+    window: Option<usize>,
+}
+
+impl crate::command::ReplCommand for DiagCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{builder::RangedU64ValueParser, Arg, Command};
+
+        Command::new("diag")
+            .about("Print Bevy diagnostics such as FPS, frame time and entity count")
+            .arg(
+                Arg::new("filter")
+                    .help("Only show diagnostics whose path contains this text, e.g. fps"),
+            )
+            .arg(
+                Arg::new("window")
+                    .short('w')
+                    .long("window")
+                    .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+                    .help("Compute min/avg/max over the last N samples [default: all kept]"),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            filter: matches.get_one::<String>("filter").cloned(),
+            window: matches.get_one::<usize>("window").copied(),
+        })
+    }
+}
+
+fn on_diag(
+    trigger: On<DiagCommand>,
+    store: Option<Res<DiagnosticsStore>>,
+    mut tracker: ResMut<ReplCommandTracker>,
+) {
+    let cmd = trigger.event();
+    let Some(store) = store else {
+        let error = "no diagnostics found, is DiagnosticsPlugin added?".to_string();
+        repl_println!("diag: {error}");
+        tracker.fail(error);
+        return;
+    };
+
+    let mut rows: Vec<(String, String)> = store
+        .iter()
+        .filter(|diagnostic| diagnostic.is_enabled)
+        .filter(|diagnostic| {
+            cmd.filter
+                .as_ref()
+                .is_none_or(|filter| diagnostic.path().as_str().contains(filter.as_str()))
+        })
+        .map(|diagnostic| {
+            (
+                diagnostic.path().to_string(),
+                describe(diagnostic, cmd.window),
+            )
+        })
+        .collect();
+    if rows.is_empty() {
+        let error = match &cmd.filter {
+            Some(filter) => format!("no diagnostics matching '{filter}'"),
+            None => "no diagnostics registered".to_string(),
+        };
+        repl_println!("diag: {error}");
+        tracker.fail(error);
+        return;
+    }
+    rows.sort();
+    let width = rows.iter().map(|(path, _)| path.len()).max().unwrap_or(0);
+    for (path, line) in rows {
+        repl_println!("{path:width$}  {line}");
+    }
+}
+
+/// The latest value of a diagnostic, followed by min/avg/max over the last
+/// `window` samples when it keeps more than one.
+fn describe(diagnostic: &Diagnostic, window: Option<usize>) -> String {
+    let suffix = &diagnostic.suffix;
+    let Some(latest) = diagnostic.value() else {
+        return "(no samples yet)".to_string();
+    };
+    let mut line = format!("{}{suffix}", format_value(latest));
+
+    let values: Vec<f64> = diagnostic.values().copied().collect();
+    let start = values.len().saturating_sub(window.unwrap_or(values.len()));
+    let samples: Vec<f64> = values[start..]
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .collect();
+    if samples.len() > 1 {
+        let min = samples.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let avg = samples.iter().sum::<f64>() / samples.len() as f64;
+        line.push_str(&format!(
+            "  min {}{suffix}  avg {}{suffix}  max {}{suffix}  ({} samples)",
+            format_value(min),
+            format_value(avg),
+            format_value(max),
+            samples.len()
+        ));
+    }
+    line
+}
+
+/// Whole numbers (counts) without decimals, everything else with two.
+fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+#[cfg(test)]
+mod diag_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+    use bevy::diagnostic::{DiagnosticMeasurement, DiagnosticPath, FrameCountPlugin};
+    use bevy::time::TimePlugin;
+    use std::time::Instant;
+
+    #[test]
+    fn test_diag_adds_missing_plugins() {
+        // DiagnosticsPlugin before the REPL, as with DefaultPlugins
+        let app = app((DiagnosticsPlugin, plugin));
+        assert!(app.is_plugin_added::<FrameTimeDiagnosticsPlugin>());
+        assert!(app.is_plugin_added::<EntityCountDiagnosticsPlugin>());
+    }
+
+    #[test]
+    fn test_diag_prints_entity_count() {
+        let mut app = app((TimePlugin, FrameCountPlugin, plugin));
+        app.update();
+        let lines = run_ok(&mut app, "diag entity");
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with("entity_count  "));
+        let (outcome, _) = run(&mut app, "diag nothing_here");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+
+    #[test]
+    fn test_describe_window() {
+        let mut diagnostic = Diagnostic::new(DiagnosticPath::const_new("test"));
+        for value in [1.0, 2.0, 6.0] {
+            diagnostic.add_measurement(DiagnosticMeasurement {
+                time: Instant::now(),
+                value,
+            });
+        }
+        assert_eq!(
+            describe(&diagnostic, None),
+            "6  min 1  avg 3  max 6  (3 samples)"
+        );
+        assert_eq!(
+            describe(&diagnostic, Some(2)),
+            "6  min 2  avg 4  max 6  (2 samples)"
+        );
+        assert_eq!(format_value(0.125), "0.13");
+    }
+}
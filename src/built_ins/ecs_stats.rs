@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::ecs::component::ComponentId;
+use bevy::prelude::*;
+
+/// Most component names printed for one archetype.
+const MAX_ARCHETYPE_COMPONENTS: usize = 8;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<EcsStatsCommand>();
+    app.add_observer(on_ecs_stats);
+}
+
+#[derive(Event, Clone, Default)]
+struct EcsStatsCommand {
+    top: usize,
+}
+
+impl crate::command::ReplCommand for EcsStatsCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{value_parser, Arg, Command};
+
+        Command::new("ecs-stats")
+            .about("Print archetype, table and component storage statistics")
+            .arg(
+                Arg::new("top")
+                    .short('n')
+                    .long("top")
+                    .value_parser(value_parser!(usize))
+                    .default_value("10")
+                    .help("How many components and archetypes to list"),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            top: matches.get_one::<usize>("top").copied().unwrap_or(10),
+        })
+    }
+}
+
+fn on_ecs_stats(trigger: On<EcsStatsCommand>, mut commands: Commands) {
+    let top = trigger.event().top;
+    commands.queue(move |world: &mut World| print_stats(world, top));
+}
+
+fn print_stats(world: &World, top: usize) {
+    let archetypes = world.archetypes();
+    let entities: usize = archetypes.iter().map(|a| a.len() as usize).sum();
+    let empty = archetypes.iter().filter(|a| a.is_empty()).count();
+    repl_println!(
+        "{entities} entities in {} archetypes ({empty} empty) and {} tables",
+        archetypes.len(),
+        world.storages().tables.len()
+    );
+
+    // Entities per component, over every archetype that has it
+    let mut counts: HashMap<ComponentId, usize> = HashMap::new();
+    for archetype in archetypes.iter() {
+        for &id in archetype.components() {
+            *counts.entry(id).or_default() += archetype.len() as usize;
+        }
+    }
+    let mut components: Vec<(String, usize, usize)> = counts
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .filter_map(|(id, count)| {
+            let info = world.components().get_info(id)?;
+            let bytes = info.layout().size() * count;
+            Some((info.name().shortname().to_string(), count, bytes))
+        })
+        .collect();
+    components.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)).then(a.0.cmp(&b.0)));
+    if !components.is_empty() {
+        let total: usize = components.iter().map(|(_, _, bytes)| bytes).sum();
+        repl_println!(
+            "components by entity count (~{} of component data):",
+            format_bytes(total)
+        );
+        let shown = &components[..components.len().min(top)];
+        let width = shown.iter().map(|(name, ..)| name.len()).max().unwrap_or(0);
+        for (name, count, bytes) in shown {
+            repl_println!("  {name:width$}  {count:>7}  ~{}", format_bytes(*bytes));
+        }
+        if components.len() > shown.len() {
+            repl_println!("  ... {} more", components.len() - shown.len());
+        }
+    }
+
+    let mut largest: Vec<_> = archetypes.iter().filter(|a| !a.is_empty()).collect();
+    largest.sort_by_key(|a| std::cmp::Reverse(a.len()));
+    if !largest.is_empty() {
+        repl_println!("largest archetypes:");
+        for archetype in largest.iter().take(top) {
+            let names: Vec<String> = archetype
+                .components()
+                .iter()
+                .filter_map(|&id| world.components().get_info(id))
+                .map(|info| info.name().shortname().to_string())
+                .collect();
+            let more = names.len().saturating_sub(MAX_ARCHETYPE_COMPONENTS);
+            let mut list = names[..names.len() - more].join(", ");
+            if more > 0 {
+                list.push_str(&format!(", ... {more} more"));
+            }
+            repl_println!(
+                "  #{:<5} {:>7} entities  [{list}]",
+                archetype.id().index(),
+                archetype.len()
+            );
+        }
+    }
+}
+
+/// Bytes in the largest unit that keeps the number at or above one.
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod ecs_stats_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run_ok};
+
+    #[derive(Component)]
+    struct Pos(#[allow(dead_code)] u64);
+
+    #[derive(Component)]
+    struct Vel(#[allow(dead_code)] u32);
+
+    fn stats_app() -> App {
+        let mut app = app(plugin);
+        let world = app.world_mut();
+        world.spawn_batch((0..5).map(Pos));
+        world.spawn_batch((0..2).map(|i| (Pos(i), Vel(i as u32))));
+        app
+    }
+
+    /// The count and size columns of a component's line.
+    fn component_row<'a>(lines: &'a [String], name: &str) -> Vec<&'a str> {
+        lines
+            .iter()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>())
+            .find(|words| words.first() == Some(&name))
+            .map(|words| words[1..].to_vec())
+            .unwrap_or_else(|| panic!("no line for {name} in {lines:?}"))
+    }
+
+    #[test]
+    fn test_ecs_stats_counts() {
+        let mut app = stats_app();
+        let lines = run_ok(&mut app, "ecs-stats");
+        let entities = app.world().entities().len();
+        assert!(lines[0].starts_with(&format!("{entities} entities in ")));
+        assert_eq!(component_row(&lines, "Pos"), vec!["7", "~56", "B"]);
+        assert_eq!(component_row(&lines, "Vel"), vec!["2", "~8", "B"]);
+        assert!(lines
+            .iter()
+            .any(|line| line.ends_with(" 5 entities  [Pos]")));
+        assert!(lines
+            .iter()
+            .any(|line| line.ends_with(" 2 entities  [Pos, Vel]")));
+    }
+
+    #[test]
+    fn test_ecs_stats_top() {
+        let mut app = stats_app();
+        let lines = run_ok(&mut app, "ecs-stats --top 1");
+        let components = lines
+            .iter()
+            .position(|line| line.starts_with("components"))
+            .unwrap();
+        assert_eq!(component_row(&lines, "Pos"), vec!["7", "~56", "B"]);
+        assert!(lines[components + 2].starts_with("  ... "));
+        let archetypes = lines
+            .iter()
+            .position(|line| line == "largest archetypes:")
+            .unwrap();
+        assert_eq!(lines.len(), archetypes + 2);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,241 @@
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::reflection::{
+    find_registration, format_inline, parse_ron_or_default, ReflectReplMessage,
+};
+use crate::repl_println;
+use bevy::ecs::reflect::ReflectEvent;
+use bevy::prelude::*;
+use bevy::reflect::{ReflectFromReflect, TypeRegistration, TypeRegistry};
+
+/// Widest a printed value may get before it is cut.
+const MAX_VALUE_WIDTH: usize = 120;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<TriggerCommand>();
+    app.add_repl_command::<SendCommand>();
+    app.add_observer(on_trigger);
+    app.add_observer(on_send);
+}
+
+/// `<name> <type> [value]`, the arguments `trigger` and `send` share.
+fn clap_command(name: &'static str, about: &'static str, example: &'static str) -> clap::Command {
+    use clap::{Arg, Command};
+
+    Command::new(name)
+        .about(about)
+        .arg(
+            Arg::new("type")
+                .required(true)
+                .help(format!("Type name, e.g. {example}")),
+        )
+        .arg(
+            Arg::new("value")
+                .num_args(1..)
+                .allow_hyphen_values(true)
+                .help("Value in RON, e.g. (amount: 5.0); the type's Default if omitted"),
+        )
+        .after_help("Values are parsed as RON. Quotes around strings are optional.")
+}
+
+fn type_and_value(matches: &clap::ArgMatches) -> (String, Option<String>) {
+    (
+        matches
+            .get_one::<String>("type")
+            .cloned()
+            .unwrap_or_default(),
+        matches
+            .get_many::<String>("value")
+            .map(|v| v.cloned().collect::<Vec<_>>().join(" ")),
+    )
+}
+
+#[derive(Event, Clone, Default)]
+struct TriggerCommand {
+    event: String,
+    value: Option<String>,
+}
+
+impl crate::command::ReplCommand for TriggerCommand {
+    fn clap_command() -> clap::Command {
+        clap_command(
+            "trigger",
+            "Trigger a reflected event for its observers",
+            "LevelUp or my_game::LevelUp",
+        )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        let (event, value) = type_and_value(matches);
+        Ok(Self { event, value })
+    }
+}
+
+#[derive(Event, Clone, Default)]
+struct SendCommand {
+    message: String,
+    value: Option<String>,
+}
+
+impl crate::command::ReplCommand for SendCommand {
+    fn clap_command() -> clap::Command {
+        clap_command(
+            "send",
+            "Write a reflected message to Messages<T>",
+            "DamageEvent or my_game::DamageEvent",
+        )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        let (message, value) = type_and_value(matches);
+        Ok(Self { message, value })
+    }
+}
+
+fn on_trigger(trigger: On<TriggerCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = trigger_event(world, &cmd.event, cmd.value.as_deref()) {
+            repl_println!("trigger: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn on_send(trigger: On<SendCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = send_message(world, &cmd.message, cmd.value.as_deref()) {
+            repl_println!("send: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn trigger_event(world: &mut World, name: &str, value: Option<&str>) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let registration = find_registration(&registry, name)?;
+    let reflect = registration
+        .data::<ReflectEvent>()
+        .ok_or_else(|| format!("{name} is not a reflected event (add #[reflect(Event)] to it)"))?;
+    let event = build_value(&registry, registration, value)?;
+    // Observers may print, so announce the event first
+    repl_println!(
+        "triggered {}",
+        format_inline(event.as_partial_reflect(), MAX_VALUE_WIDTH)
+    );
+    reflect.trigger(world, event.as_partial_reflect(), &registry);
+    Ok(())
+}
+
+fn send_message(world: &mut World, name: &str, value: Option<&str>) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let registration = find_registration(&registry, name)?;
+    let reflect = registration.data::<ReflectReplMessage>().ok_or_else(|| {
+        format!("{name} is not a reflected message (add #[reflect(ReplMessage)] to it)")
+    })?;
+    let message = build_value(&registry, registration, value)?;
+    reflect.write(world, message.as_partial_reflect())?;
+    repl_println!(
+        "sent {}",
+        format_inline(message.as_partial_reflect(), MAX_VALUE_WIDTH)
+    );
+    Ok(())
+}
+
+/// Parse the value and build the concrete type from it, so that a value that
+/// doesn't fit the type is reported instead of reaching `ReflectEvent`.
+fn build_value(
+    registry: &TypeRegistry,
+    registration: &TypeRegistration,
+    value: Option<&str>,
+) -> Result<Box<dyn Reflect>, String> {
+    let value = parse_ron_or_default(registry, registration, value)?;
+    let name = registration.type_info().type_path_table().short_path();
+    registration
+        .data::<ReflectFromReflect>()
+        .and_then(|from_reflect| from_reflect.from_reflect(value.as_ref()))
+        .or_else(|| value.try_into_reflect().ok())
+        .ok_or_else(|| format!("could not build a {name} from that value"))
+}
+
+#[cfg(test)]
+mod events_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[derive(Event, Reflect, Default, Clone)]
+    #[reflect(Event, Default)]
+    struct LevelUp {
+        level: u32,
+    }
+
+    #[derive(Message, Reflect, Default, Clone)]
+    #[reflect(ReplMessage, Default)]
+    struct Damage {
+        amount: f32,
+    }
+
+    /// What the observer and the reader received.
+    #[derive(Resource, Default)]
+    struct Received {
+        levels: Vec<u32>,
+        damage: Vec<f32>,
+    }
+
+    fn on_level_up(event: On<LevelUp>, mut received: ResMut<Received>) {
+        received.levels.push(event.level);
+    }
+
+    fn read_damage(mut messages: MessageReader<Damage>, mut received: ResMut<Received>) {
+        received
+            .damage
+            .extend(messages.read().map(|damage| damage.amount));
+    }
+
+    fn setup(app: &mut App) {
+        app.register_type::<LevelUp>()
+            .register_type::<Damage>()
+            .add_message::<Damage>()
+            .init_resource::<Received>()
+            .add_observer(on_level_up)
+            .add_systems(Update, read_damage);
+    }
+
+    #[test]
+    fn test_trigger_reaches_observer() {
+        let mut app = app((plugin, setup));
+        let lines = run_ok(&mut app, "trigger LevelUp (level: 3)");
+        assert_eq!(lines, vec!["triggered LevelUp { level: 3 }"]);
+        run_ok(&mut app, "trigger LevelUp");
+        assert_eq!(app.world().resource::<Received>().levels, vec![3, 0]);
+    }
+
+    #[test]
+    fn test_send_reaches_reader() {
+        let mut app = app((plugin, setup));
+        let lines = run_ok(&mut app, "send Damage (amount: 5.0)");
+        assert_eq!(lines, vec!["sent Damage { amount: 5.0 }"]);
+        app.update();
+        assert_eq!(app.world().resource::<Received>().damage, vec![5.0]);
+    }
+
+    #[test]
+    fn test_events_reject_wrong_kind_and_value() {
+        let mut app = app((plugin, setup));
+        for line in [
+            "trigger Damage",
+            "send LevelUp",
+            "trigger LevelUp (level: \"x\")",
+        ] {
+            let (outcome, _) = run(&mut app, line);
+            assert!(matches!(outcome, CommandOutcome::Failed(_)), "{line}");
+        }
+        app.update();
+        let received = app.world().resource::<Received>();
+        assert!(received.levels.is_empty() && received.damage.is_empty());
+    }
+}
@@ -0,0 +1,181 @@
+use crate::command::{parse_entity_selector, parse_name_regex, ReplCommandTracker};
+use crate::prelude::*;
+use crate::reflection::find_component;
+use crate::repl_println;
+use bevy::ecs::query::QueryBuilder;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<FindCommand>();
+    app.add_observer(on_find);
+}
+
+#[derive(Event, Clone, Default)]
+struct FindCommand {
+    pattern: String,
+    regex: bool,
+    with: Vec<String>,
+    limit: usize,
+}
+
+impl crate::command::ReplCommand for FindCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{value_parser, Arg, ArgAction, Command};
+
+        Command::new("find")
+            .about("Find entities by name")
+            .arg(
+                Arg::new("pattern")
+                    .required(true)
+                    .help("Name, glob such as 'Enemy*', or /regex/"),
+            )
+            .arg(
+                Arg::new("regex")
+                    .short('r')
+                    .long("regex")
+                    .action(ArgAction::SetTrue)
+                    .help("Treat the pattern as a regular expression, without slashes"),
+            )
+            .arg(
+                Arg::new("with")
+                    .short('w')
+                    .long("with")
+                    .value_delimiter(',')
+                    .action(ArgAction::Append)
+                    .help("Only entities with these components"),
+            )
+            .arg(
+                Arg::new("limit")
+                    .short('n')
+                    .long("limit")
+                    .value_parser(value_parser!(usize))
+                    .default_value("50")
+                    .help("Print at most this many entities"),
+            )
+            .after_help(
+                "Every command that takes an entity accepts the same patterns, \
+                 e.g. despawn 'Bullet*'.",
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            pattern: matches
+                .get_one::<String>("pattern")
+                .cloned()
+                .unwrap_or_default(),
+            regex: matches.get_flag("regex"),
+            with: matches
+                .get_many::<String>("with")
+                .map(|values| values.filter(|v| !v.is_empty()).cloned().collect())
+                .unwrap_or_default(),
+            limit: matches.get_one::<usize>("limit").copied().unwrap_or(50),
+        })
+    }
+}
+
+fn on_find(trigger: On<FindCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = run_find(world, &cmd) {
+            repl_println!("find: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn run_find(world: &mut World, cmd: &FindCommand) -> Result<(), String> {
+    let selector = if cmd.regex {
+        parse_name_regex(&cmd.pattern)?
+    } else {
+        parse_entity_selector(&cmd.pattern)?
+    };
+
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let mut with = Vec::new();
+    for name in &cmd.with {
+        with.push(find_component(world, &registry, name)?.0);
+    }
+    let mut builder = QueryBuilder::<(Entity, Option<&Name>)>::new(world);
+    for id in with {
+        builder.with_id(id);
+    }
+    let mut query = builder.build();
+    let mut found: Vec<(Entity, String)> = query
+        .iter(world)
+        .filter(|(entity, name)| selector.matches(*entity, *name))
+        .map(|(entity, name)| (entity, name.map(Name::to_string).unwrap_or_default()))
+        .collect();
+    found.sort_by_key(|(entity, _)| (entity.index_u32(), entity.generation().to_bits()));
+
+    let total = found.len();
+    if total == 0 {
+        repl_println!("no entity matches {selector}");
+        return Ok(());
+    }
+    let width = found
+        .iter()
+        .take(cmd.limit)
+        .map(|(entity, _)| entity.to_string().len())
+        .max()
+        .unwrap_or(0);
+    for (entity, name) in found.iter().take(cmd.limit) {
+        repl_println!("{:width$}  {name}", entity.to_string());
+    }
+    if total > cmd.limit {
+        repl_println!("... {} more (use --limit to show more)", total - cmd.limit);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod find_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Boss;
+
+    fn find_app() -> App {
+        let mut app = app(plugin);
+        app.register_type::<Boss>();
+        let world = app.world_mut();
+        world.spawn(Name::new("Enemy1"));
+        world.spawn((Name::new("Enemy2"), Boss));
+        world.spawn(Name::new("Player"));
+        app
+    }
+
+    fn names(lines: &[String]) -> Vec<&str> {
+        lines
+            .iter()
+            .filter_map(|line| line.split_once("  ").map(|(_, name)| name))
+            .collect()
+    }
+
+    #[test]
+    fn test_find_by_glob_and_regex() {
+        let mut app = find_app();
+        let lines = run_ok(&mut app, "find Enemy*");
+        assert_eq!(names(&lines), vec!["Enemy1", "Enemy2"]);
+        let lines = run_ok(&mut app, "find /^Pl/");
+        assert_eq!(names(&lines), vec!["Player"]);
+        let lines = run_ok(&mut app, r"find -r '^Enemy\d$' --with Boss");
+        assert_eq!(names(&lines), vec!["Enemy2"]);
+    }
+
+    #[test]
+    fn test_find_limit_and_no_match() {
+        let mut app = find_app();
+        let lines = run_ok(&mut app, "find * -n 1");
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1], "... 2 more (use --limit to show more)");
+        let lines = run_ok(&mut app, "find Nobody");
+        assert!(lines[0].starts_with("no entity matches"));
+        let (outcome, _) = run(&mut app, "find -r (");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+}
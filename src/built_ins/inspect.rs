@@ -0,0 +1,206 @@
+use crate::command::{parse_entity_selector, EntitySelector, ReplCommandTracker};
+use crate::prelude::*;
+use crate::reflection::{format_tree, TreeLimits};
+use crate::repl_println;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<InspectCommand>();
+    app.add_observer(on_inspect);
+}
+
+#[derive(Event, Clone, Default)]
+struct InspectCommand {
+    entity: EntitySelector,
+    limits: TreeLimits,
+    brief: bool,
+}
+
+impl crate::command::ReplCommand for InspectCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{value_parser, Arg, ArgAction, Command};
+
+        let defaults = TreeLimits::default();
+        Command::new("inspect")
+            .about("Print an entity's components and their fields")
+            .arg(
+                Arg::new("entity")
+                    .required(true)
+                    .value_parser(parse_entity_selector)
+                    .help("Entity ID (e.g. 12v1) or Name"),
+            )
+            .arg(
+                Arg::new("depth")
+                    .short('d')
+                    .long("depth")
+                    .value_parser(value_parser!(usize))
+                    .help(format!(
+                        "How many levels of nested fields to expand [default: {}]",
+                        defaults.max_depth
+                    )),
+            )
+            .arg(
+                Arg::new("items")
+                    .short('n')
+                    .long("items")
+                    .value_parser(value_parser!(usize))
+                    .help(format!(
+                        "How many items of each collection to print [default: {}]",
+                        defaults.max_items
+                    )),
+            )
+            .arg(
+                Arg::new("brief")
+                    .short('b')
+                    .long("brief")
+                    .action(ArgAction::SetTrue)
+                    .help("Only list the components"),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        let defaults = TreeLimits::default();
+        Ok(Self {
+            entity: matches
+                .get_one::<EntitySelector>("entity")
+                .cloned()
+                .unwrap_or_default(),
+            limits: TreeLimits {
+                max_depth: matches
+                    .get_one::<usize>("depth")
+                    .copied()
+                    .unwrap_or(defaults.max_depth),
+                max_items: matches
+                    .get_one::<usize>("items")
+                    .copied()
+                    .unwrap_or(defaults.max_items),
+            },
+            brief: matches.get_flag("brief"),
+        })
+    }
+}
+
+fn on_inspect(trigger: On<InspectCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        let entities = cmd.entity.resolve(world);
+        if entities.is_empty() {
+            let error = format!("no entity matches {}", cmd.entity);
+            repl_println!("inspect: {error}");
+            world.resource_mut::<ReplCommandTracker>().fail(error);
+            return;
+        }
+        for entity in entities {
+            inspect_entity(world, entity, &cmd);
+        }
+    });
+}
+
+fn inspect_entity(world: &World, entity: Entity, cmd: &InspectCommand) {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let entity_ref = world.entity(entity);
+    let components = entity_ref.archetype().components();
+
+    let title = match entity_ref.get::<Name>() {
+        Some(name) => format!("{name} ({entity})"),
+        None => entity.to_string(),
+    };
+    let count = components.len();
+    repl_println!(
+        "{title}: {count} component{}",
+        if count == 1 { "" } else { "s" }
+    );
+
+    for &id in components {
+        let Some(info) = world.components().get_info(id) else {
+            continue;
+        };
+        let registration = info.type_id().and_then(|type_id| registry.get(type_id));
+        let (short, full) = match registration {
+            Some(registration) => {
+                let paths = registration.type_info().type_path_table();
+                (paths.short_path().to_string(), paths.path().to_string())
+            }
+            None => {
+                let name = info.name();
+                (name.shortname().to_string(), name.to_string())
+            }
+        };
+        let size = info.layout().size();
+        let reflected = registration
+            .and_then(|registration| registration.data::<ReflectComponent>())
+            .and_then(|reflect| reflect.reflect(entity_ref));
+        let note = if reflected.is_some() {
+            ""
+        } else {
+            ", not reflected"
+        };
+        repl_println!("  {short} ({full}, {size} bytes{note})");
+
+        if cmd.brief {
+            continue;
+        }
+        if let Some(value) = reflected {
+            for line in format_tree(value.as_partial_reflect(), cmd.limits) {
+                repl_println!("  {line}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod inspect_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health {
+        hp: f32,
+    }
+
+    #[derive(Component)]
+    struct Opaque;
+
+    fn player_app() -> App {
+        let mut app = app(plugin);
+        app.register_type::<Health>().register_type::<Name>();
+        app.world_mut()
+            .spawn((Name::new("Player"), Health { hp: 10.0 }, Opaque));
+        app
+    }
+
+    #[test]
+    fn test_inspect_prints_fields() {
+        let mut app = player_app();
+        let lines = run_ok(&mut app, "inspect Player");
+        assert!(lines[0].starts_with("Player ("));
+        assert!(lines[0].ends_with("3 components"));
+        assert!(lines
+            .iter()
+            .any(|line| line.trim_start().starts_with("Health (")));
+        assert!(lines
+            .iter()
+            .any(|line| line.contains("hp") && line.contains("10")));
+        assert!(lines
+            .iter()
+            .any(|line| line.contains("Opaque") && line.contains("not reflected")));
+    }
+
+    #[test]
+    fn test_inspect_brief_skips_fields() {
+        let mut app = player_app();
+        let lines = run_ok(&mut app, "inspect Player --brief");
+        assert_eq!(lines.len(), 4);
+        assert!(!lines.iter().any(|line| line.contains("hp")));
+    }
+
+    #[test]
+    fn test_inspect_unknown_entity_fails() {
+        let mut app = player_app();
+        let (outcome, _) = run(&mut app, "inspect Nobody");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+}
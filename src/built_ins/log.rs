@@ -0,0 +1,224 @@
+use crate::command::ReplCommandTracker;
+use crate::log_ecs::ReplLogFilter;
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::log::tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use bevy::log::LogPlugin;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<LogCommand>();
+    app.add_observer(on_log);
+    // The REPL's layer lets everything through until now; start it from what
+    // `LogPlugin` filters with, so `log` shows what is actually captured
+    let Some(directives) = app
+        .get_added_plugins::<LogPlugin>()
+        .first()
+        .copied()
+        .map(log_plugin_directives)
+    else {
+        return;
+    };
+    let result = match app.world_mut().get_resource_mut::<ReplLogFilter>() {
+        Some(mut filter) => filter.reset_to(directives),
+        None => Ok(()),
+    };
+    if let Err(e) = result {
+        warn!("could not start the REPL log filter from LogPlugin: {e}");
+    }
+}
+
+/// The directives `LogPlugin` filters with: `RUST_LOG` if it is set and
+/// valid, like `LogPlugin` does, and otherwise its `level` and `filter`.
+fn log_plugin_directives(log_plugin: &LogPlugin) -> String {
+    let from_env = std::env::var(EnvFilter::DEFAULT_ENV)
+        .ok()
+        .filter(|directives| EnvFilter::builder().parse(directives).is_ok());
+    if let Some(directives) = from_env {
+        return directives;
+    }
+    let level = LevelFilter::from_level(log_plugin.level).to_string();
+    if log_plugin.filter.is_empty() {
+        level
+    } else {
+        format!("{level},{}", log_plugin.filter)
+    }
+}
+
+#[derive(Event, Clone, Default)]
+enum LogCommand {
+    /// Print the active filter.
+    #[default]
+    Show,
+    /// Set the default level, keeping per-target directives.
+    Level(String),
+    /// Add or replace per-target directives.
+    Filter(String),
+    /// Restore the filter the log layer was installed with.
+    Reset,
+}
+
+impl crate::command::ReplCommand for LogCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{Arg, Command};
+
+        Command::new("log")
+            .about("Show or change the log filter at runtime")
+            .subcommand(Command::new("show").about("Print the active filter directives"))
+            .subcommand(
+                Command::new("level")
+                    .about("Set the default log level")
+                    .arg(
+                        Arg::new("level")
+                            .required(true)
+                            .value_parser(["off", "error", "warn", "info", "debug", "trace"]),
+                    ),
+            )
+            .subcommand(
+                Command::new("filter")
+                    .about("Add or replace per-target directives")
+                    .arg(
+                        Arg::new("directives")
+                            .required(true)
+                            .help("EnvFilter directives, e.g. my_game::ai=trace,wgpu=error"),
+                    ),
+            )
+            .subcommand(Command::new("reset").about("Restore the default filter"))
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        let string =
+            |m: &clap::ArgMatches, id: &str| m.get_one::<String>(id).cloned().unwrap_or_default();
+        Ok(match matches.subcommand() {
+            Some(("level", m)) => Self::Level(string(m, "level")),
+            Some(("filter", m)) => Self::Filter(string(m, "directives")),
+            Some(("reset", _)) => Self::Reset,
+            _ => Self::Show,
+        })
+    }
+}
+
+fn on_log(
+    trigger: On<LogCommand>,
+    filter: Option<Res<ReplLogFilter>>,
+    mut tracker: ResMut<ReplCommandTracker>,
+) {
+    let result = match filter {
+        Some(filter) => run_log(&filter, trigger.event()),
+        None => Err(
+            "no reloadable log filter installed (use tracing_to_repl_fmt or repl_log_custom_layer)"
+                .to_string(),
+        ),
+    };
+    if let Err(e) = result {
+        repl_println!("log: {e}");
+        tracker.fail(e);
+    }
+}
+
+fn run_log(filter: &ReplLogFilter, cmd: &LogCommand) -> Result<(), String> {
+    match cmd {
+        LogCommand::Show => {}
+        LogCommand::Level(level) => {
+            let mut directives = split_directives(&filter.directives()?);
+            directives.retain(|directive| !is_level(directive));
+            directives.insert(0, level.clone());
+            filter.set(&directives.join(","))?;
+        }
+        LogCommand::Filter(new) => {
+            let mut directives = split_directives(&filter.directives()?);
+            for directive in split_directives(new) {
+                directives.retain(|old| is_level(old) || target(old) != target(&directive));
+                directives.push(directive);
+            }
+            filter.set(&directives.join(","))?;
+        }
+        LogCommand::Reset => filter.reset()?,
+    }
+    repl_println!("log filter: {}", filter.directives()?);
+    Ok(())
+}
+
+fn split_directives(directives: &str) -> Vec<String> {
+    directives
+        .split(',')
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// A bare level such as `info` applies to every target.
+fn is_level(directive: &str) -> bool {
+    directive.parse::<LevelFilter>().is_ok()
+}
+
+/// The part of a directive before its level, e.g. `my_game::ai` or
+/// `my_game[span{id=1}]`.
+fn target(directive: &str) -> &str {
+    match directive.rsplit_once('=') {
+        Some((target, level)) if level.parse::<LevelFilter>().is_ok() => target,
+        _ => directive,
+    }
+}
+
+#[cfg(test)]
+mod log_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[test]
+    fn test_directive_target() {
+        assert!(is_level("info"));
+        assert!(!is_level("my_game=info"));
+        assert_eq!(target("my_game::ai=trace"), "my_game::ai");
+        assert_eq!(target("my_game::ai"), "my_game::ai");
+        assert_eq!(
+            split_directives(" info, ,wgpu=error "),
+            vec!["info", "wgpu=error"]
+        );
+    }
+
+    #[test]
+    fn test_log_changes_filter() {
+        // The handle only works while its layer is alive
+        let (_layer, filter) = ReplLogFilter::new("info");
+        let mut app = app(plugin);
+        app.insert_resource(filter.clone());
+        assert_eq!(run_ok(&mut app, "log"), vec!["log filter: info"]);
+
+        run_ok(&mut app, "log level debug");
+        let directives = filter.directives().unwrap();
+        assert!(directives.contains("debug") && !directives.contains("info"));
+
+        run_ok(&mut app, "log filter my_game::ai=trace");
+        run_ok(&mut app, "log filter my_game::ai=warn");
+        let directives = filter.directives().unwrap();
+        assert!(directives.contains("my_game::ai=warn"));
+        assert!(!directives.contains("trace"));
+        assert!(directives.contains("debug"));
+
+        run_ok(&mut app, "log reset");
+        assert_eq!(filter.directives().unwrap(), "info");
+    }
+
+    #[test]
+    fn test_log_without_filter_fails() {
+        let mut app = app(plugin);
+        let (outcome, _) = run(&mut app, "log");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+
+    #[test]
+    fn test_log_plugin_directives() {
+        let log_plugin = LogPlugin {
+            level: bevy::log::Level::DEBUG,
+            filter: "wgpu=error".to_string(),
+            ..default()
+        };
+        if std::env::var(EnvFilter::DEFAULT_ENV).is_err() {
+            assert_eq!(log_plugin_directives(&log_plugin), "debug,wgpu=error");
+        }
+    }
+}
@@ -9,6 +9,81 @@ mod clear;
 #[cfg(feature = "help")]
 mod help;
 
+#[cfg(feature = "time")]
+mod time;
+
+#[cfg(feature = "resource")]
+mod resource;
+
+#[cfg(feature = "inspect")]
+mod inspect;
+
+#[cfg(feature = "query")]
+mod query;
+
+#[cfg(feature = "component")]
+mod component;
+
+#[cfg(feature = "spawn")]
+mod spawn;
+
+#[cfg(feature = "state")]
+mod state;
+
+#[cfg(feature = "clock")]
+mod clock;
+
+#[cfg(feature = "schedules")]
+mod schedules;
+
+#[cfg(feature = "log")]
+mod log;
+
+#[cfg(feature = "watch_messages")]
+mod watch_messages;
+
+#[cfg(feature = "diag")]
+mod diag;
+
+#[cfg(feature = "watch")]
+mod watch;
+
+#[cfg(any(feature = "watch", feature = "watch_messages"))]
+mod unwatch;
+
+#[cfg(feature = "scene")]
+mod scene;
+
+#[cfg(feature = "assets")]
+mod assets;
+
+#[cfg(feature = "tree")]
+mod tree;
+
+#[cfg(feature = "find")]
+mod find;
+
+#[cfg(feature = "events")]
+mod events;
+
+#[cfg(feature = "run")]
+mod run;
+
+#[cfg(feature = "ecs_stats")]
+mod ecs_stats;
+
+#[cfg(feature = "snapshot")]
+mod snapshot;
+
+/// Leaves out the entities Bevy uses internally: observers and registered
+/// systems. Commands that list entities without a filter apply it so their
+/// output isn't buried under them.
+#[cfg(any(feature = "query", feature = "tree"))]
+pub(crate) type NotInternal = (
+    Without<Observer>,
+    Without<bevy::ecs::system::SystemIdMarker>,
+);
+
 pub struct ReplDefaultCommandsPlugin;
 
 impl Plugin for ReplDefaultCommandsPlugin {
@@ -20,6 +95,114 @@ impl Plugin for ReplDefaultCommandsPlugin {
             clear::plugin,
             #[cfg(feature = "help")]
             help::plugin,
+            #[cfg(feature = "time")]
+            time::plugin,
+            #[cfg(feature = "resource")]
+            resource::plugin,
+            #[cfg(feature = "inspect")]
+            inspect::plugin,
+            #[cfg(feature = "query")]
+            query::plugin,
+            #[cfg(feature = "component")]
+            component::plugin,
+            #[cfg(feature = "spawn")]
+            spawn::plugin,
+            #[cfg(feature = "state")]
+            state::plugin,
+            #[cfg(feature = "clock")]
+            clock::plugin,
+            #[cfg(feature = "schedules")]
+            schedules::plugin,
+            #[cfg(feature = "log")]
+            log::plugin,
+            #[cfg(feature = "watch_messages")]
+            watch_messages::plugin,
+        ));
+        // Plugin tuples are limited in length, so later commands go in a
+        // second call
+        app.add_plugins((
+            #[cfg(feature = "diag")]
+            diag::plugin,
+            #[cfg(feature = "watch")]
+            watch::plugin,
+            #[cfg(any(feature = "watch", feature = "watch_messages"))]
+            unwatch::plugin,
+            #[cfg(feature = "scene")]
+            scene::plugin,
+            #[cfg(feature = "assets")]
+            assets::plugin,
+            #[cfg(feature = "tree")]
+            tree::plugin,
+            #[cfg(feature = "find")]
+            find::plugin,
+            #[cfg(feature = "events")]
+            events::plugin,
+            #[cfg(feature = "run")]
+            run::plugin,
+            #[cfg(feature = "ecs_stats")]
+            ecs_stats::plugin,
+            #[cfg(feature = "snapshot")]
+            snapshot::plugin,
         ));
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    //! Running built-in commands against a bare [`App`] in tests.
+
+    use crate::command::{dispatch_command, CommandOutcome, ReplCommandTracker};
+    use crate::print::capture_output;
+    use crate::repl::Repl;
+    use bevy::app::Plugins;
+    use bevy::prelude::*;
+
+    /// An app with the REPL's command table and `plugins`, finished like
+    /// `App::run` would and updated once so their commands are registered.
+    pub(crate) fn app<M>(plugins: impl Plugins<M>) -> App {
+        let mut app = App::new();
+        app.init_resource::<Repl>()
+            .init_resource::<ReplCommandTracker>()
+            .add_plugins(plugins);
+        app.finish();
+        app.cleanup();
+        app.update();
+        app
+    }
+
+    /// Dispatch `input` as if typed at the prompt, and update the app once if
+    /// the command deferred its completion. Returns how it ended and the lines
+    /// it printed.
+    pub(crate) fn run(app: &mut App, input: &str) -> (CommandOutcome, Vec<String>) {
+        let (handle, lines) = capture_output(|| {
+            let handle = dispatch_command(app.world_mut(), input)?;
+            if app
+                .world()
+                .resource::<ReplCommandTracker>()
+                .is_running(handle)
+            {
+                app.update();
+            }
+            Some(handle)
+        });
+        let handle = handle.expect("input should not be empty");
+        let outcome = app
+            .world()
+            .resource::<ReplCommandTracker>()
+            .outcome(handle)
+            .cloned()
+            .unwrap_or_else(|| panic!("'{input}' should have finished"));
+        (outcome, lines)
+    }
+
+    /// Like [`run`], but panics unless the command succeeds.
+    pub(crate) fn run_ok(app: &mut App, input: &str) -> Vec<String> {
+        let (outcome, lines) = run(app, input);
+        assert_eq!(
+            outcome,
+            CommandOutcome::Success,
+            "'{input}' printed {lines:?}"
+        );
+        lines
+    }
+}
@@ -0,0 +1,278 @@
+use super::NotInternal;
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::reflection::{find_component, format_inline};
+use crate::repl_println;
+use bevy::ecs::query::QueryBuilder;
+use bevy::prelude::*;
+use bevy::reflect::GetPath;
+
+/// Widest a single table cell may get before it is cut.
+const MAX_CELL_WIDTH: usize = 48;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<QueryCommand>();
+    app.add_observer(on_query);
+}
+
+#[derive(Event, Clone, Default)]
+struct QueryCommand {
+    with: Vec<String>,
+    without: Vec<String>,
+    show: Vec<String>,
+    count: bool,
+    limit: usize,
+}
+
+impl crate::command::ReplCommand for QueryCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{value_parser, Arg, ArgAction, Command};
+
+        Command::new("query")
+            .about("List entities by the components they have")
+            .arg(
+                Arg::new("with")
+                    .short('w')
+                    .long("with")
+                    .value_delimiter(',')
+                    .action(ArgAction::Append)
+                    .help("Only entities with these components"),
+            )
+            .arg(
+                Arg::new("without")
+                    .short('x')
+                    .long("without")
+                    .value_delimiter(',')
+                    .action(ArgAction::Append)
+                    .help("Only entities without these components"),
+            )
+            .arg(
+                Arg::new("show")
+                    .short('s')
+                    .long("show")
+                    .value_delimiter(',')
+                    .action(ArgAction::Append)
+                    .help("Components (or Component.field paths) to print as columns"),
+            )
+            .arg(
+                Arg::new("count")
+                    .short('c')
+                    .long("count")
+                    .action(ArgAction::SetTrue)
+                    .help("Only print the number of matching entities"),
+            )
+            .arg(
+                Arg::new("limit")
+                    .short('n')
+                    .long("limit")
+                    .value_parser(value_parser!(usize))
+                    .default_value("50")
+                    .help("Print at most this many entities"),
+            )
+            .after_help(
+                "Components are named by their short or full type path and must be \
+                 registered for reflection.\n\
+                 Example: query --with Player --without Dead --show Transform.translation,Health",
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        let names = |id: &str| -> Vec<String> {
+            matches
+                .get_many::<String>(id)
+                .map(|values| values.filter(|v| !v.is_empty()).cloned().collect())
+                .unwrap_or_default()
+        };
+        Ok(Self {
+            with: names("with"),
+            without: names("without"),
+            show: names("show"),
+            count: matches.get_flag("count"),
+            limit: matches.get_one::<usize>("limit").copied().unwrap_or(50),
+        })
+    }
+}
+
+fn on_query(trigger: On<QueryCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = run_query(world, &cmd) {
+            repl_println!("query: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn run_query(world: &mut World, cmd: &QueryCommand) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let mut with = Vec::new();
+    for name in &cmd.with {
+        with.push(find_component(world, &registry, name)?.0);
+    }
+    let mut without = Vec::new();
+    for name in &cmd.without {
+        without.push(find_component(world, &registry, name)?.0);
+    }
+    // Each column is a component plus an optional field path inside it
+    let mut columns = Vec::new();
+    for column in &cmd.show {
+        let (name, path) = match column.split_once('.') {
+            Some((name, path)) => (name, path),
+            None => (column.as_str(), ""),
+        };
+        let (_, reflect) = find_component(world, &registry, name)?;
+        columns.push((column.as_str(), reflect, path));
+    }
+
+    // Observers and registered systems would otherwise swamp an unfiltered query
+    let mut builder = QueryBuilder::<Entity, NotInternal>::new(world);
+    for id in with {
+        builder.with_id(id);
+    }
+    for id in without {
+        builder.without_id(id);
+    }
+    let mut query = builder.build();
+    let mut entities: Vec<Entity> = query.iter(world).collect();
+    entities.sort_by_key(|entity| (entity.index_u32(), entity.generation().to_bits()));
+
+    let total = entities.len();
+    if cmd.count {
+        repl_println!("{total}");
+        return Ok(());
+    }
+    if total == 0 {
+        repl_println!("no matching entities");
+        return Ok(());
+    }
+
+    let mut header = vec!["entity".to_string(), "name".to_string()];
+    header.extend(columns.iter().map(|(column, ..)| column.to_string()));
+    let mut rows = vec![header];
+    for &entity in entities.iter().take(cmd.limit) {
+        let entity_ref = world.entity(entity);
+        let mut row = vec![
+            entity.to_string(),
+            entity_ref
+                .get::<Name>()
+                .map(|name| name.to_string())
+                .unwrap_or_default(),
+        ];
+        for (_, reflect, path) in &columns {
+            let cell = match reflect.reflect(entity_ref) {
+                Some(value) if path.is_empty() => {
+                    format_inline(value.as_partial_reflect(), MAX_CELL_WIDTH)
+                }
+                Some(value) => match value.reflect_path(*path) {
+                    Ok(field) => format_inline(field, MAX_CELL_WIDTH),
+                    Err(e) => format!("<{e}>"),
+                },
+                None => "-".to_string(),
+            };
+            row.push(cell);
+        }
+        rows.push(row);
+    }
+
+    for line in format_table(&rows) {
+        repl_println!("{line}");
+    }
+    if total > cmd.limit {
+        repl_println!("... {} more (use --limit to show more)", total - cmd.limit);
+    }
+    Ok(())
+}
+
+/// Left-align cells into columns separated by two spaces.
+fn format_table(rows: &[Vec<String>]) -> Vec<String> {
+    let mut widths = Vec::new();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            let width = cell.chars().count();
+            match widths.get_mut(i) {
+                Some(w) if *w < width => *w = width,
+                Some(_) => {}
+                None => widths.push(width),
+            }
+        }
+    }
+    rows.iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect();
+            cells.join("  ").trim_end().to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod query_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health {
+        hp: f32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Dead;
+
+    fn enemies_app() -> App {
+        let mut app = app(plugin);
+        app.register_type::<Health>().register_type::<Dead>();
+        let world = app.world_mut();
+        world.spawn((Name::new("Orc"), Health { hp: 5.0 }));
+        world.spawn((Name::new("Goblin"), Health { hp: 2.0 }));
+        world.spawn((Name::new("Ghost"), Health { hp: 0.0 }, Dead));
+        world.spawn(Name::new("Rock"));
+        app
+    }
+
+    #[test]
+    fn test_query_count_with_and_without() {
+        let mut app = enemies_app();
+        // The command's own observer isn't listed
+        assert_eq!(run_ok(&mut app, "query --count"), vec!["4"]);
+        assert_eq!(run_ok(&mut app, "query --with Health --count"), vec!["3"]);
+        assert_eq!(
+            run_ok(&mut app, "query -w Health -x Dead --count"),
+            vec!["2"]
+        );
+    }
+
+    #[test]
+    fn test_query_show_field_and_limit() {
+        let mut app = enemies_app();
+        let lines = run_ok(&mut app, "query -w Health -x Dead -s Health.hp -n 1");
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("entity"));
+        assert!(lines[0].ends_with("Health.hp"));
+        assert!(lines[1].contains("Orc") && lines[1].ends_with("5.0"));
+        assert_eq!(lines[2], "... 1 more (use --limit to show more)");
+    }
+
+    #[test]
+    fn test_query_unknown_component_fails() {
+        let mut app = enemies_app();
+        let (outcome, _) = run(&mut app, "query --with Mana");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+
+    #[test]
+    fn test_format_table_aligns_columns() {
+        let rows = vec![
+            vec!["entity".to_string(), "name".to_string()],
+            vec!["0v0".to_string(), "Orc".to_string()],
+        ];
+        assert_eq!(format_table(&rows), vec!["entity  name", "0v0     Orc"]);
+    }
+}
@@ -0,0 +1,222 @@
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::reflection::{find_registration, format_tree, set_from_ron, TreeLimits};
+use crate::repl_println;
+use bevy::ecs::reflect::ReflectResource;
+use bevy::prelude::*;
+use bevy::reflect::GetPath;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<ResourceCommand>();
+    app.add_observer(on_resource);
+}
+
+#[derive(Event, Clone)]
+enum ResourceCommand {
+    /// List reflected resources, optionally filtered by name.
+    List(Option<String>),
+    /// Print a resource as a tree.
+    Get(String),
+    /// Set a resource, or a field of it, from RON.
+    Set { path: String, value: String },
+}
+
+impl Default for ResourceCommand {
+    fn default() -> Self {
+        Self::List(None)
+    }
+}
+
+impl crate::command::ReplCommand for ResourceCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{Arg, Command};
+
+        Command::new("resource")
+            .about("Inspect and modify reflected resources")
+            .subcommand(
+                Command::new("list")
+                    .about("List resources registered for reflection")
+                    .arg(Arg::new("filter").help("Only show names containing this")),
+            )
+            .subcommand(
+                Command::new("get")
+                    .about("Print a resource")
+                    .arg(Arg::new("name").required(true).help("e.g. GameConfig")),
+            )
+            .subcommand(
+                Command::new("set")
+                    .about("Set a resource or one of its fields")
+                    .arg(
+                        Arg::new("path")
+                            .required(true)
+                            .help("Resource and field path, e.g. GameConfig.difficulty"),
+                    )
+                    .arg(
+                        Arg::new("value")
+                            .required(true)
+                            .num_args(1..)
+                            .allow_hyphen_values(true)
+                            .help("New value in RON, e.g. 3, \"Bob\" or (1.0, 2.0, 0.0)"),
+                    ),
+            )
+            .after_help("Values are parsed as RON. Quotes around strings are optional.")
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(match matches.subcommand() {
+            Some(("get", m)) => Self::Get(m.get_one::<String>("name").cloned().unwrap_or_default()),
+            Some(("set", m)) => Self::Set {
+                path: m.get_one::<String>("path").cloned().unwrap_or_default(),
+                value: m
+                    .get_many::<String>("value")
+                    .map(|v| v.cloned().collect::<Vec<_>>().join(" "))
+                    .unwrap_or_default(),
+            },
+            Some(("list", m)) => Self::List(m.get_one::<String>("filter").cloned()),
+            _ => Self::default(),
+        })
+    }
+}
+
+fn on_resource(trigger: On<ResourceCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        let result = match &cmd {
+            ResourceCommand::List(filter) => list_resources(world, filter.as_deref()),
+            ResourceCommand::Get(name) => get_resource(world, name),
+            ResourceCommand::Set { path, value } => set_resource(world, path, value),
+        };
+        if let Err(e) = result {
+            repl_println!("resource: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn list_resources(world: &World, filter: Option<&str>) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let filter = filter.map(str::to_lowercase);
+    let mut names: Vec<(&str, bool)> = registry
+        .iter_with_data::<ReflectResource>()
+        .map(|(registration, reflect)| {
+            let name = registration.type_info().type_path_table().short_path();
+            (name, reflect.reflect(world).is_ok())
+        })
+        .filter(|(name, _)| {
+            filter
+                .as_ref()
+                .is_none_or(|f| name.to_lowercase().contains(f))
+        })
+        .collect();
+    if names.is_empty() {
+        return Err("no matching resources are registered for reflection".to_string());
+    }
+    names.sort();
+    for (name, present) in names {
+        if present {
+            repl_println!("{name}");
+        } else {
+            repl_println!("{name} (not inserted)");
+        }
+    }
+    Ok(())
+}
+
+fn get_resource(world: &World, name: &str) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let registration = find_registration(&registry, name)?;
+    let reflect = registration
+        .data::<ReflectResource>()
+        .ok_or_else(|| format!("{name} is not a reflected resource"))?;
+    let value = reflect
+        .reflect(world)
+        .map_err(|_| format!("{name} is not inserted in the world"))?;
+    repl_println!(
+        "{}",
+        registration.type_info().type_path_table().short_path()
+    );
+    for line in format_tree(value.as_partial_reflect(), TreeLimits::NONE) {
+        repl_println!("{line}");
+    }
+    Ok(())
+}
+
+fn set_resource(world: &mut World, path: &str, value: &str) -> Result<(), String> {
+    let (name, field_path) = match path.split_once('.') {
+        Some((name, field_path)) => (name, field_path),
+        None => (path, ""),
+    };
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let registration = find_registration(&registry, name)?;
+    let reflect = registration
+        .data::<ReflectResource>()
+        .ok_or_else(|| format!("{name} is not a reflected resource"))?;
+    let mut resource = reflect
+        .reflect_mut(&mut *world)
+        .map_err(|_| format!("{name} is not inserted in the world"))?;
+    let target = if field_path.is_empty() {
+        resource.as_partial_reflect_mut()
+    } else {
+        resource
+            .reflect_path_mut(field_path)
+            .map_err(|e| e.to_string())?
+    };
+    set_from_ron(&registry, target, value)?;
+    repl_println!("{path} = {target:?}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod resource_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct GameConfig {
+        difficulty: u32,
+        name: String,
+    }
+
+    fn config_app() -> App {
+        let mut app = app(plugin);
+        app.register_type::<GameConfig>()
+            .insert_resource(GameConfig {
+                difficulty: 1,
+                name: "easy".to_string(),
+            });
+        app
+    }
+
+    #[test]
+    fn test_resource_set_field() {
+        let mut app = config_app();
+        run_ok(&mut app, "resource set GameConfig.difficulty 3");
+        run_ok(&mut app, "resource set GameConfig.name Bob");
+        let config = app.world().resource::<GameConfig>();
+        assert_eq!(config.difficulty, 3);
+        assert_eq!(config.name, "Bob");
+    }
+
+    #[test]
+    fn test_resource_get_and_list() {
+        let mut app = config_app();
+        let lines = run_ok(&mut app, "resource get GameConfig");
+        assert_eq!(lines[0], "GameConfig");
+        assert!(lines.iter().any(|line| line.contains("difficulty")));
+        let lines = run_ok(&mut app, "resource list game");
+        assert_eq!(lines, vec!["GameConfig".to_string()]);
+    }
+
+    #[test]
+    fn test_resource_unknown_fails() {
+        let mut app = config_app();
+        let (outcome, _) = run(&mut app, "resource get Nope");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        let (outcome, _) = run(&mut app, "resource set GameConfig.difficulty abc");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        assert_eq!(app.world().resource::<GameConfig>().difficulty, 1);
+    }
+}
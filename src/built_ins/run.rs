@@ -0,0 +1,134 @@
+use crate::command::diagnostic::closest_match;
+use crate::command::{ReplCommandTracker, ReplOneshots};
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<RunCommand>();
+    app.add_observer(on_run);
+    app.init_resource::<ReplOneshots>();
+    // Also present without the prompt plugins, e.g. in headless apps
+    app.init_resource::<ReplCompletions>();
+    app.add_systems(
+        Update,
+        complete_oneshot_names.run_if(resource_changed::<ReplOneshots>),
+    );
+}
+
+#[derive(Event, Clone, Default)]
+struct RunCommand {
+    name: Option<String>,
+}
+
+impl crate::command::ReplCommand for RunCommand {
+    fn clap_command() -> clap::Command {
+        clap::Command::new("run")
+            .about("Run a one-shot system registered with app.add_repl_oneshot")
+            .arg(
+                clap::Arg::new("name")
+                    .help("Name the system was registered under; lists them if omitted"),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            name: matches.get_one::<String>("name").cloned(),
+        })
+    }
+}
+
+fn on_run(trigger: On<RunCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        let result = match &cmd.name {
+            Some(name) => run_oneshot(world, name),
+            None => {
+                list_oneshots(world);
+                Ok(())
+            }
+        };
+        if let Err(e) = result {
+            repl_println!("run: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+/// Offer the registered names for `run <Tab>`.
+fn complete_oneshot_names(oneshots: Res<ReplOneshots>, mut completions: ResMut<ReplCompletions>) {
+    completions.set_arguments("run", oneshots.0.keys().cloned());
+}
+
+fn list_oneshots(world: &World) {
+    let oneshots = world.resource::<ReplOneshots>();
+    if oneshots.0.is_empty() {
+        repl_println!("no one-shot systems registered (add them with app.add_repl_oneshot)");
+        return;
+    }
+    for name in oneshots.0.keys() {
+        repl_println!("{name}");
+    }
+}
+
+fn run_oneshot(world: &mut World, name: &str) -> Result<(), String> {
+    let oneshots = world.resource::<ReplOneshots>();
+    let Some(&id) = oneshots.0.get(name) else {
+        let known = oneshots.0.keys().map(String::as_str);
+        return Err(match closest_match(name, known) {
+            Some(similar) => {
+                format!("no one-shot system named '{name}', did you mean '{similar}'?")
+            }
+            None => format!("no one-shot system named '{name}' (type 'run' to list them)"),
+        });
+    };
+    world.run_system(id).map_err(|e| format!("{name}: {e}"))?;
+    repl_println!("ran {name}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod run_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[derive(Resource, Default)]
+    struct Resets(u32);
+
+    fn reset_map(mut resets: ResMut<Resets>) {
+        resets.0 += 1;
+    }
+
+    fn setup(app: &mut App) {
+        app.init_resource::<Resets>()
+            .add_repl_oneshot("reset_map", reset_map)
+            .add_repl_oneshot("reset_ai", || {});
+    }
+
+    #[test]
+    fn test_run_oneshot() {
+        let mut app = app((plugin, setup));
+        assert_eq!(run_ok(&mut app, "run reset_map"), vec!["ran reset_map"]);
+        run_ok(&mut app, "run reset_map");
+        assert_eq!(app.world().resource::<Resets>().0, 2);
+    }
+
+    #[test]
+    fn test_run_lists_and_suggests() {
+        let mut app = app((plugin, setup));
+        assert_eq!(run_ok(&mut app, "run"), vec!["reset_ai", "reset_map"]);
+        let (outcome, _) = run(&mut app, "run reset_mpa");
+        assert!(
+            matches!(outcome, CommandOutcome::Failed(e) if e.contains("did you mean 'reset_map'"))
+        );
+        assert_eq!(app.world().resource::<Resets>().0, 0);
+    }
+
+    #[test]
+    fn test_run_completes_names() {
+        let app = app((plugin, setup));
+        let completions = app.world().resource::<ReplCompletions>();
+        assert_eq!(completions.0["run"], vec!["reset_ai", "reset_map"]);
+    }
+}
@@ -0,0 +1,311 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::reflection::find_component;
+use crate::repl_println;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::prelude::*;
+use bevy::reflect::serde::TypedReflectSerializer;
+use bevy::reflect::TypeRegistry;
+use bevy::scene::serde::SceneDeserializer;
+use bevy::scene::{DynamicScene, DynamicSceneBuilder, SceneFilter};
+use serde::de::DeserializeSeed;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<SceneCommand>();
+    app.add_observer(on_scene);
+}
+
+#[derive(Event, Clone)]
+enum SceneCommand {
+    /// Write entities (and optionally resources) to a scene file.
+    Save {
+        path: PathBuf,
+        components: Vec<String>,
+        resources: bool,
+    },
+    /// Spawn the entities of a scene file.
+    Load { path: PathBuf },
+}
+
+impl Default for SceneCommand {
+    fn default() -> Self {
+        Self::Load {
+            path: PathBuf::new(),
+        }
+    }
+}
+
+impl crate::command::ReplCommand for SceneCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{value_parser, Arg, ArgAction, Command};
+
+        let path = Arg::new("path")
+            .required(true)
+            .value_parser(value_parser!(PathBuf))
+            .help("Scene file, e.g. bug-1234.scn.ron");
+        Command::new("scene")
+            .about("Save the world to a scene file, or spawn one back")
+            .subcommand_required(true)
+            .subcommand(
+                Command::new("save")
+                    .about("Write reflected components to a .scn.ron file")
+                    .arg(path.clone())
+                    .arg(
+                        Arg::new("filter")
+                            .short('f')
+                            .long("filter")
+                            .value_delimiter(',')
+                            .action(ArgAction::Append)
+                            .help("Only save these components (and entities that have them)"),
+                    )
+                    .arg(
+                        Arg::new("resources")
+                            .short('r')
+                            .long("resources")
+                            .action(ArgAction::SetTrue)
+                            .help("Also save reflected resources"),
+                    ),
+            )
+            .subcommand(
+                Command::new("load")
+                    .about("Spawn the entities of a .scn.ron file")
+                    .arg(path),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        let path = |m: &clap::ArgMatches| m.get_one::<PathBuf>("path").cloned().unwrap_or_default();
+        Ok(match matches.subcommand() {
+            Some(("save", m)) => Self::Save {
+                path: path(m),
+                components: m
+                    .get_many::<String>("filter")
+                    .map(|names| names.cloned().collect())
+                    .unwrap_or_default(),
+                resources: m.get_flag("resources"),
+            },
+            Some(("load", m)) => Self::Load { path: path(m) },
+            _ => Self::default(),
+        })
+    }
+}
+
+fn on_scene(trigger: On<SceneCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        let result = match &cmd {
+            SceneCommand::Save {
+                path,
+                components,
+                resources,
+            } => save_scene(world, path, components, *resources),
+            SceneCommand::Load { path } => load_scene(world, path),
+        };
+        if let Err(e) = result {
+            repl_println!("scene: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn save_scene(
+    world: &mut World,
+    path: &Path,
+    components: &[String],
+    resources: bool,
+) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let filter = if components.is_empty() {
+        SceneFilter::allow_all()
+    } else {
+        let mut filter = SceneFilter::deny_all();
+        for name in components {
+            let (id, _) = find_component(world, &registry, name)?;
+            let type_id = world
+                .components()
+                .get_info(id)
+                .and_then(|info| info.type_id())
+                .ok_or_else(|| format!("{name} has no Rust type"))?;
+            filter = filter.allow_by_id(type_id);
+        }
+        filter
+    };
+
+    let entities: Vec<Entity> = world
+        .archetypes()
+        .iter()
+        .flat_map(|archetype| archetype.entities())
+        .map(|entity| entity.id())
+        .collect();
+    let mut builder = DynamicSceneBuilder::from_world(world)
+        .with_component_filter(filter)
+        .extract_entities(entities.into_iter())
+        .remove_empty_entities();
+    if resources {
+        builder = builder.extract_resources();
+    }
+    let mut scene = builder.build();
+
+    let mut skipped = BTreeSet::new();
+    retain_serializable(&mut scene.resources, &registry, &mut skipped);
+    for entity in &mut scene.entities {
+        retain_serializable(&mut entity.components, &registry, &mut skipped);
+    }
+    scene
+        .entities
+        .retain(|entity| !entity.components.is_empty());
+    if !skipped.is_empty() {
+        let skipped: Vec<_> = skipped.into_iter().collect();
+        repl_println!(
+            "skipped types that can't be serialized: {}",
+            skipped.join(", ")
+        );
+    }
+
+    let ron = scene
+        .serialize(&registry)
+        .map_err(|e| format!("could not serialize the scene: {e}"))?;
+    std::fs::write(path, ron).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    let components: usize = scene
+        .entities
+        .iter()
+        .map(|entity| entity.components.len())
+        .sum();
+    repl_println!(
+        "saved {} entities ({components} components){} to {}",
+        scene.entities.len(),
+        resource_count(&scene),
+        path.display()
+    );
+    Ok(())
+}
+
+fn load_scene(world: &mut World, path: &Path) -> Result<(), String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let scene = {
+        let registry = registry.read();
+        let mut ron = ron::Deserializer::from_str(&text).map_err(|e| e.to_string())?;
+        SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut ron)
+        .map_err(|e| format!("invalid scene {}: {e}", path.display()))?
+    };
+
+    // Every entity is spawned new; references between them are remapped
+    let mut entity_map = EntityHashMap::default();
+    scene
+        .write_to_world(world, &mut entity_map)
+        .map_err(|e| e.to_string())?;
+    repl_println!(
+        "spawned {} entities{} from {}",
+        entity_map.len(),
+        resource_count(&scene),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Drop values that fail to serialize, e.g. because they contain a type
+/// without `ReflectSerialize` such as `Instant`, and collect their type names.
+fn retain_serializable(
+    values: &mut Vec<Box<dyn PartialReflect>>,
+    registry: &TypeRegistry,
+    skipped: &mut BTreeSet<String>,
+) {
+    values.retain(|value| {
+        let serializer = TypedReflectSerializer::new(value.as_ref(), registry);
+        let ok = ron::to_string(&serializer).is_ok();
+        if !ok {
+            skipped.insert(value.reflect_short_type_path().to_string());
+        }
+        ok
+    });
+}
+
+fn resource_count(scene: &DynamicScene) -> String {
+    match scene.resources.len() {
+        0 => String::new(),
+        1 => " and 1 resource".to_string(),
+        n => format!(" and {n} resources"),
+    }
+}
+
+#[cfg(test)]
+mod scene_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health {
+        hp: f32,
+    }
+
+    fn scene_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("bevy_repl_{name}_{}.scn.ron", std::process::id()))
+    }
+
+    fn health(app: &mut App) -> Vec<(Entity, f32)> {
+        let world = app.world_mut();
+        let mut values: Vec<_> = world
+            .query::<(Entity, &Health)>()
+            .iter(world)
+            .map(|(entity, health)| (entity, health.hp))
+            .collect();
+        values.sort_by(|a, b| a.1.total_cmp(&b.1));
+        values
+    }
+
+    #[test]
+    fn test_scene_save_and_load() {
+        let mut app = app(plugin);
+        app.register_type::<Health>();
+        app.world_mut().spawn(Health { hp: 1.0 });
+        app.world_mut()
+            .spawn((Health { hp: 2.0 }, Name::new("Bob")));
+        let path = scene_path("save_and_load");
+
+        let lines = run_ok(
+            &mut app,
+            &format!("scene save {} -f Health", path.display()),
+        );
+        assert_eq!(
+            lines,
+            vec![format!(
+                "saved 2 entities (2 components) to {}",
+                path.display()
+            )]
+        );
+        for (entity, _) in health(&mut app) {
+            app.world_mut().despawn(entity);
+        }
+
+        let lines = run_ok(&mut app, &format!("scene load {}", path.display()));
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(
+            lines,
+            vec![format!("spawned 2 entities from {}", path.display())]
+        );
+        let hp: Vec<f32> = health(&mut app).into_iter().map(|(_, hp)| hp).collect();
+        assert_eq!(hp, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_scene_errors() {
+        let mut app = app(plugin);
+        let path = scene_path("errors");
+        let (outcome, _) = run(&mut app, &format!("scene save {} -f Mana", path.display()));
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        assert!(!path.exists());
+        let (outcome, _) = run(&mut app, &format!("scene load {}", path.display()));
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+}
@@ -0,0 +1,437 @@
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use crate::command::{CommandHandle, CommandOutcome, ReplCommandTracker};
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::ecs::schedule::graph::Direction;
+use bevy::ecs::schedule::{
+    InternedScheduleLabel, NodeId, Schedule, ScheduleGraph, ScheduleLabel, Schedules, SystemKey,
+};
+use bevy::ecs::system::ScheduleSystem;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<SchedulesCommand>();
+    app.add_repl_command::<SystemsCommand>();
+    app.add_observer(on_schedules);
+    app.add_observer(on_systems);
+    app.init_resource::<PendingScheduleRequests>();
+    app.add_systems(Last, run_schedule_requests);
+}
+
+#[derive(Event, Clone, Default)]
+struct SchedulesCommand;
+
+impl crate::command::ReplCommand for SchedulesCommand {
+    fn clap_command() -> clap::Command {
+        clap::Command::new("schedules").about("List the schedules in the world")
+    }
+}
+
+#[derive(Event, Clone, Default)]
+struct SystemsCommand {
+    schedule: String,
+    full_names: bool,
+    dot: Option<PathBuf>,
+}
+
+impl crate::command::ReplCommand for SystemsCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{value_parser, Arg, ArgAction, Command};
+
+        Command::new("systems")
+            .about("List a schedule's systems with their sets and ordering")
+            .arg(
+                Arg::new("schedule")
+                    .required(true)
+                    .help("Schedule label as listed by 'schedules', e.g. Update"),
+            )
+            .arg(
+                Arg::new("full")
+                    .short('f')
+                    .long("full")
+                    .action(ArgAction::SetTrue)
+                    .help("Print full type paths instead of short names"),
+            )
+            .arg(
+                Arg::new("dot")
+                    .long("dot")
+                    .value_name("FILE")
+                    .value_parser(value_parser!(PathBuf))
+                    .help("Write the schedule graph to FILE in Graphviz DOT format"),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            schedule: matches
+                .get_one::<String>("schedule")
+                .cloned()
+                .unwrap_or_default(),
+            full_names: matches.get_flag("full"),
+            dot: matches.get_one::<PathBuf>("dot").cloned(),
+        })
+    }
+}
+
+enum ScheduleRequest {
+    List,
+    Systems(SystemsCommand),
+}
+
+/// Requests waiting for [`Last`]. Schedules are taken out of [`Schedules`]
+/// while they run, so commands (which are dispatched from `Update`) can't see
+/// `Update` itself; `Last` can see everything except `Main` and `Last`.
+#[derive(Resource, Default)]
+struct PendingScheduleRequests(Vec<(ScheduleRequest, CommandHandle)>);
+
+fn on_schedules(
+    _trigger: On<SchedulesCommand>,
+    mut tracker: ResMut<ReplCommandTracker>,
+    mut pending: ResMut<PendingScheduleRequests>,
+) {
+    if let Some(handle) = tracker.defer() {
+        pending.0.push((ScheduleRequest::List, handle));
+    }
+}
+
+fn on_systems(
+    trigger: On<SystemsCommand>,
+    mut tracker: ResMut<ReplCommandTracker>,
+    mut pending: ResMut<PendingScheduleRequests>,
+) {
+    if let Some(handle) = tracker.defer() {
+        pending
+            .0
+            .push((ScheduleRequest::Systems(trigger.event().clone()), handle));
+    }
+}
+
+fn run_schedule_requests(world: &mut World) {
+    let requests = std::mem::take(&mut world.resource_mut::<PendingScheduleRequests>().0);
+    for (request, handle) in requests {
+        let result = match request {
+            ScheduleRequest::List => {
+                list_schedules(world);
+                Ok(())
+            }
+            ScheduleRequest::Systems(cmd) => list_systems(world, &cmd),
+        };
+        let outcome = match result {
+            Ok(()) => CommandOutcome::Success,
+            Err(e) => {
+                repl_println!("systems: {e}");
+                CommandOutcome::Failed(e)
+            }
+        };
+        world
+            .resource_mut::<ReplCommandTracker>()
+            .complete(handle, outcome);
+    }
+}
+
+/// Schedules by name, including the ones that are running right now.
+fn schedule_labels(world: &World) -> Vec<(InternedScheduleLabel, Option<&Schedule>)> {
+    let schedules = world.resource::<Schedules>();
+    let mut labels: Vec<InternedScheduleLabel> = schedules
+        .iter()
+        .map(|(_, schedule)| schedule.label())
+        .collect();
+    for running in [Main.intern(), Last.intern()] {
+        if !labels.contains(&running) {
+            labels.push(running);
+        }
+    }
+    labels.sort_by_key(|label| format!("{label:?}"));
+    labels
+        .into_iter()
+        .map(|label| (label, schedules.get(label)))
+        .collect()
+}
+
+fn list_schedules(world: &World) {
+    let rows: Vec<(String, String)> = schedule_labels(world)
+        .into_iter()
+        .map(|(label, schedule)| {
+            let detail = match schedule {
+                Some(schedule) => {
+                    let count = schedule.systems_len();
+                    format!("{count} system{}", if count == 1 { "" } else { "s" })
+                }
+                None => "running, can't be inspected".to_string(),
+            };
+            (format!("{label:?}"), detail)
+        })
+        .collect();
+    let width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    for (label, detail) in rows {
+        repl_println!("{label:width$}  {detail}");
+    }
+}
+
+fn find_schedule<'w>(world: &'w World, name: &str) -> Result<&'w Schedule, String> {
+    let labels = schedule_labels(world);
+    let (label, schedule) = labels
+        .iter()
+        .find(|(label, _)| format!("{label:?}").eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("no schedule named '{name}' (see 'schedules')"))?;
+    schedule.ok_or_else(|| format!("{label:?} is running and can't be inspected"))
+}
+
+fn list_systems(world: &World, cmd: &SystemsCommand) -> Result<(), String> {
+    let schedule = find_schedule(world, &cmd.schedule)?;
+    let graph = schedule.graph();
+    let names = NodeNames::new(schedule, cmd.full_names);
+
+    repl_println!(
+        "{:?}: {} system{}",
+        schedule.label(),
+        names.systems.len(),
+        if names.systems.len() == 1 { "" } else { "s" }
+    );
+    for (i, (key, name)) in names.systems.iter().enumerate() {
+        repl_println!("{:>4}. {name}", i + 1);
+        let node = NodeId::System(*key);
+        let sets = graph
+            .hierarchy()
+            .graph()
+            .neighbors_directed(node, Direction::Incoming)
+            .filter_map(|set| names.get(set))
+            .collect();
+        let ordered = |direction| {
+            graph
+                .dependency()
+                .graph()
+                .neighbors_directed(node, direction)
+                .flat_map(|other| names.resolve(other))
+                .filter_map(|other| names.get(other))
+                .collect::<Vec<_>>()
+        };
+        let details = [
+            ("in", sets),
+            ("after", ordered(Direction::Incoming)),
+            ("before", ordered(Direction::Outgoing)),
+        ];
+        for (key, values) in details {
+            if !values.is_empty() {
+                repl_println!("        {key}: {}", values.join(", "));
+            }
+        }
+    }
+
+    if let Some(path) = &cmd.dot {
+        std::fs::write(path, to_dot(schedule, &names))
+            .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+        repl_println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// Display names for the nodes of a schedule graph.
+struct NodeNames<'g> {
+    graph: &'g ScheduleGraph,
+    /// Systems in executable order once the schedule has run (insertion
+    /// order before). Building a schedule moves its systems out of the graph,
+    /// so their names are collected here up front.
+    systems: Vec<(SystemKey, String)>,
+}
+
+impl<'g> NodeNames<'g> {
+    fn new(schedule: &'g Schedule, full: bool) -> Self {
+        let name = |system: &ScheduleSystem| {
+            let name = system.name();
+            if full {
+                name.to_string()
+            } else {
+                name.shortname().to_string()
+            }
+        };
+        let graph = schedule.graph();
+        let systems = match schedule.systems() {
+            Ok(systems) => systems.map(|(key, system)| (key, name(system))).collect(),
+            Err(_) => graph
+                .systems
+                .iter()
+                .map(|(key, system, _)| (key, name(system)))
+                .collect(),
+        };
+        Self { graph, systems }
+    }
+
+    /// Every system is also in a set named after its own type, which is what
+    /// `.after(system)` orders against. Resolve those sets to their systems.
+    fn resolve(&self, node: NodeId) -> Vec<NodeId> {
+        if !self.is_type_set(node) {
+            return vec![node];
+        }
+        self.graph
+            .hierarchy()
+            .graph()
+            .neighbors_directed(node, Direction::Outgoing)
+            .filter(|member| member.is_system())
+            .collect()
+    }
+
+    /// Whether `node` is the set named after a system's type.
+    fn is_type_set(&self, node: NodeId) -> bool {
+        node.as_set().is_some_and(|key| {
+            self.graph
+                .system_sets
+                .get(key)
+                .is_some_and(|set| set.system_type().is_some())
+        })
+    }
+
+    /// The name of a system or set, or `None` for a system type set since it
+    /// would just repeat the system's name.
+    fn get(&self, node: NodeId) -> Option<String> {
+        match node {
+            NodeId::System(key) => self
+                .systems
+                .iter()
+                .find(|(system, _)| *system == key)
+                .map(|(_, name)| name.clone()),
+            NodeId::Set(key) => {
+                let set = self.graph.system_sets.get(key)?;
+                if set.system_type().is_some() {
+                    None
+                } else if set.is_anonymous() {
+                    Some("(anonymous set)".to_string())
+                } else {
+                    Some(format!("{set:?}"))
+                }
+            }
+        }
+    }
+}
+
+/// Render the hierarchy (dashed, set to member) and dependency (solid,
+/// before to after) graphs of a schedule as Graphviz DOT.
+fn to_dot(schedule: &Schedule, names: &NodeNames) -> String {
+    let graph = schedule.graph();
+    let mut ids: HashMap<NodeId, usize> = HashMap::new();
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "digraph {} {{",
+        quote(&format!("{:?}", schedule.label()))
+    );
+    let _ = writeln!(out, "  rankdir=LR;");
+    let _ = writeln!(out, "  node [shape=box];");
+
+    let nodes = names
+        .systems
+        .iter()
+        .map(|(key, _)| NodeId::System(*key))
+        .chain(graph.hierarchy().graph().nodes())
+        .chain(graph.dependency().graph().nodes());
+    for node in nodes {
+        if ids.contains_key(&node) {
+            continue;
+        }
+        let Some(name) = names.get(node) else {
+            continue;
+        };
+        let id = ids.len();
+        ids.insert(node, id);
+        let shape = if node.is_set() { " shape=ellipse" } else { "" };
+        let _ = writeln!(out, "  n{id} [label={}{shape}];", quote(&name));
+    }
+
+    // A type set's members are the systems of that type, so its hierarchy
+    // edges would only point systems at themselves
+    let edges = [
+        (graph.hierarchy(), " [style=dashed]", true),
+        (graph.dependency(), "", false),
+    ];
+    for (dag, style, skip_type_sets) in edges {
+        for (from, to) in dag.graph().all_edges() {
+            if skip_type_sets && names.is_type_set(from) {
+                continue;
+            }
+            for from in names.resolve(from) {
+                for to in names.resolve(to) {
+                    if from == to {
+                        continue;
+                    }
+                    if let (Some(from), Some(to)) = (ids.get(&from), ids.get(&to)) {
+                        let _ = writeln!(out, "  n{from} -> n{to}{style};");
+                    }
+                }
+            }
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod schedules_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+
+    fn first_system() {}
+    fn second_system() {}
+
+    fn setup(app: &mut App) {
+        app.add_systems(Update, (first_system, second_system.after(first_system)));
+    }
+
+    #[test]
+    fn test_schedules_lists_update() {
+        let mut app = app((plugin, setup));
+        let lines = run_ok(&mut app, "schedules");
+        assert!(lines
+            .iter()
+            .any(|line| line.starts_with("Update ") && line.ends_with("2 systems")));
+        assert!(lines
+            .iter()
+            .any(|line| line.starts_with("Last ") && line.ends_with("can't be inspected")));
+    }
+
+    #[test]
+    fn test_systems_prints_ordering() {
+        let mut app = app((plugin, setup));
+        let lines = run_ok(&mut app, "systems update");
+        assert_eq!(lines[0], "Update: 2 systems");
+        let second = lines
+            .iter()
+            .position(|line| line.ends_with(". second_system"))
+            .unwrap();
+        assert_eq!(lines[second + 1].trim(), "after: first_system");
+        let (outcome, _) = run(&mut app, "systems Nowhere");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+
+    #[test]
+    fn test_systems_dot_has_no_self_loops() {
+        let mut app = app((plugin, setup));
+        let path =
+            std::env::temp_dir().join(format!("bevy_repl_systems_{}.dot", std::process::id()));
+        run_ok(
+            &mut app,
+            &format!("systems Update --dot {}", path.display()),
+        );
+        let dot = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert!(dot.starts_with("digraph \"Update\" {"));
+        let edges: Vec<(&str, &str)> = dot
+            .lines()
+            .filter_map(|line| line.trim().split_once(" -> "))
+            .map(|(from, to)| (from, to.trim_end_matches(';')))
+            .collect();
+        assert_eq!(edges.len(), 1);
+        assert!(edges.iter().all(|(from, to)| from != to));
+    }
+
+    #[test]
+    fn test_quote_escapes() {
+        assert_eq!(quote(r#"a "b" \c"#), r#""a \"b\" \\c""#);
+    }
+}
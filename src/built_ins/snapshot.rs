@@ -0,0 +1,606 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::Instant;
+
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::reflection::{find_registration, format_inline};
+use crate::repl_println;
+use bevy::ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy::prelude::*;
+use bevy::reflect::ReflectRef;
+
+/// Widest a printed value may get before it is cut.
+const MAX_VALUE_WIDTH: usize = 80;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<SnapshotCommand>();
+    app.add_observer(on_snapshot);
+    app.init_resource::<Snapshots>();
+}
+
+#[derive(Event, Clone, Default)]
+enum SnapshotCommand {
+    /// List the snapshots taken so far.
+    #[default]
+    List,
+    /// Capture reflected components and resources under a name.
+    Take(String),
+    /// Compare two snapshots, or a snapshot and the current world.
+    Diff {
+        from: String,
+        to: String,
+        filter: Vec<String>,
+    },
+    /// Forget a snapshot.
+    Drop(String),
+}
+
+impl crate::command::ReplCommand for SnapshotCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{Arg, ArgAction, Command};
+
+        Command::new("snapshot")
+            .about("Capture reflected world state and diff it later")
+            .subcommand(Command::new("list").about("List the snapshots taken so far"))
+            .subcommand(
+                Command::new("take")
+                    .about("Capture reflected components and resources")
+                    .arg(Arg::new("name").required(true).help("e.g. before")),
+            )
+            .subcommand(
+                Command::new("diff")
+                    .about("Print added and removed entities and changed values")
+                    .arg(
+                        Arg::new("from")
+                            .required(true)
+                            .help("Snapshot to compare from"),
+                    )
+                    .arg(
+                        Arg::new("to")
+                            .required(true)
+                            .help("Snapshot to compare to, or 'now' for the current world"),
+                    )
+                    .arg(
+                        Arg::new("filter")
+                            .short('f')
+                            .long("filter")
+                            .value_delimiter(',')
+                            .action(ArgAction::Append)
+                            .help("Only compare these components and resources"),
+                    ),
+            )
+            .subcommand(
+                Command::new("drop")
+                    .about("Forget a snapshot")
+                    .arg(Arg::new("name").required(true)),
+            )
+            .after_help("Example: snapshot take before; snapshot diff before now -f Transform")
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        let arg =
+            |m: &clap::ArgMatches, id: &str| m.get_one::<String>(id).cloned().unwrap_or_default();
+        Ok(match matches.subcommand() {
+            Some(("take", m)) => Self::Take(arg(m, "name")),
+            Some(("diff", m)) => Self::Diff {
+                from: arg(m, "from"),
+                to: arg(m, "to"),
+                filter: m
+                    .get_many::<String>("filter")
+                    .map(|names| names.filter(|n| !n.is_empty()).cloned().collect())
+                    .unwrap_or_default(),
+            },
+            Some(("drop", m)) => Self::Drop(arg(m, "name")),
+            _ => Self::List,
+        })
+    }
+}
+
+/// Reflected state of the world at one point in time.
+struct Snapshot {
+    taken: Instant,
+    entities: HashMap<Entity, EntityState>,
+    /// Resources by full type path.
+    resources: BTreeMap<String, Box<dyn Reflect>>,
+    /// Types whose values couldn't be cloned, and so weren't captured.
+    skipped: BTreeSet<String>,
+}
+
+struct EntityState {
+    name: Option<String>,
+    /// Reflected components by full type path.
+    components: BTreeMap<String, Box<dyn Reflect>>,
+}
+
+/// Snapshots by name.
+#[derive(Resource, Default)]
+struct Snapshots(BTreeMap<String, Snapshot>);
+
+fn on_snapshot(trigger: On<SnapshotCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        let result = match &cmd {
+            SnapshotCommand::List => {
+                list_snapshots(world);
+                Ok(())
+            }
+            SnapshotCommand::Take(name) => take_snapshot(world, name),
+            SnapshotCommand::Diff { from, to, filter } => diff_snapshots(world, from, to, filter),
+            SnapshotCommand::Drop(name) => match world.resource_mut::<Snapshots>().0.remove(name) {
+                Some(_) => {
+                    repl_println!("dropped snapshot '{name}'");
+                    Ok(())
+                }
+                None => Err(format!("no snapshot named '{name}'")),
+            },
+        };
+        if let Err(e) = result {
+            repl_println!("snapshot: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn list_snapshots(world: &World) {
+    let snapshots = world.resource::<Snapshots>();
+    if snapshots.0.is_empty() {
+        repl_println!("no snapshots, take one with 'snapshot take <name>'");
+        return;
+    }
+    let width = snapshots.0.keys().map(String::len).max().unwrap_or(0);
+    for (name, snapshot) in &snapshots.0 {
+        repl_println!(
+            "{name:width$}  {} entities, {} resources, taken {:.1?} ago",
+            snapshot.entities.len(),
+            snapshot.resources.len(),
+            snapshot.taken.elapsed()
+        );
+    }
+}
+
+fn take_snapshot(world: &mut World, name: &str) -> Result<(), String> {
+    if name == "now" {
+        return Err("'now' stands for the current world, pick another name".to_string());
+    }
+    let snapshot = capture(world);
+    if !snapshot.skipped.is_empty() {
+        let skipped: Vec<_> = snapshot.skipped.iter().map(String::as_str).collect();
+        repl_println!("skipped types that can't be cloned: {}", skipped.join(", "));
+    }
+    repl_println!(
+        "took snapshot '{name}': {} entities, {} resources",
+        snapshot.entities.len(),
+        snapshot.resources.len()
+    );
+    world
+        .resource_mut::<Snapshots>()
+        .0
+        .insert(name.to_string(), snapshot);
+    Ok(())
+}
+
+/// Clone every reflected component and resource in the world.
+fn capture(world: &World) -> Snapshot {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut snapshot = Snapshot {
+        taken: Instant::now(),
+        entities: HashMap::new(),
+        resources: BTreeMap::new(),
+        skipped: BTreeSet::new(),
+    };
+    for archetype in world.archetypes().iter() {
+        for archetype_entity in archetype.entities() {
+            let entity_ref = world.entity(archetype_entity.id());
+            let mut state = EntityState {
+                name: entity_ref.get::<Name>().map(Name::to_string),
+                components: BTreeMap::new(),
+            };
+            for &id in archetype.components() {
+                let Some(registration) = world
+                    .components()
+                    .get_info(id)
+                    .and_then(|info| info.type_id())
+                    .and_then(|type_id| registry.get(type_id))
+                else {
+                    continue;
+                };
+                let Some(value) = registration
+                    .data::<ReflectComponent>()
+                    .and_then(|reflect| reflect.reflect(entity_ref))
+                else {
+                    continue;
+                };
+                let paths = registration.type_info().type_path_table();
+                match value.reflect_clone() {
+                    Ok(value) => {
+                        state.components.insert(paths.path().to_string(), value);
+                    }
+                    Err(_) => {
+                        snapshot.skipped.insert(paths.short_path().to_string());
+                    }
+                }
+            }
+            snapshot.entities.insert(entity_ref.id(), state);
+        }
+    }
+    for (registration, reflect) in registry.iter_with_data::<ReflectResource>() {
+        let Ok(value) = reflect.reflect(world) else {
+            continue;
+        };
+        let paths = registration.type_info().type_path_table();
+        match value.reflect_clone() {
+            Ok(value) => {
+                snapshot.resources.insert(paths.path().to_string(), value);
+            }
+            Err(_) => {
+                snapshot.skipped.insert(paths.short_path().to_string());
+            }
+        }
+    }
+    snapshot
+}
+
+fn diff_snapshots(world: &World, from: &str, to: &str, filter: &[String]) -> Result<(), String> {
+    let filter = resolve_filter(world, filter)?;
+    let snapshots = world.resource::<Snapshots>();
+    let get = |name: &str| {
+        snapshots
+            .0
+            .get(name)
+            .ok_or_else(|| format!("no snapshot named '{name}'"))
+    };
+    let before = get(from)?;
+    let now;
+    let after = if to == "now" {
+        now = capture(world);
+        &now
+    } else {
+        get(to)?
+    };
+
+    let included = |path: &str| filter.is_empty() || filter.iter().any(|f| f == path);
+    let relevant = |state: &EntityState| {
+        filter.is_empty() || state.components.keys().any(|name| included(name))
+    };
+    let mut entities: Vec<Entity> = before
+        .entities
+        .keys()
+        .chain(after.entities.keys())
+        .copied()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    entities.sort_by_key(|entity| (entity.index_u32(), entity.generation().to_bits()));
+
+    let (mut added, mut removed, mut changed) = (0, 0, 0);
+    for entity in entities {
+        match (before.entities.get(&entity), after.entities.get(&entity)) {
+            (None, Some(state)) if relevant(state) => {
+                added += 1;
+                repl_println!("+ {}", label(entity, state));
+            }
+            (Some(state), None) if relevant(state) => {
+                removed += 1;
+                repl_println!("- {}", label(entity, state));
+            }
+            (Some(old), Some(new)) => {
+                let lines = diff_maps(&old.components, &new.components, &included);
+                if !lines.is_empty() {
+                    changed += 1;
+                    repl_println!("~ {}", label(entity, new));
+                    for line in lines {
+                        repl_println!("    {line}");
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let resource_lines = diff_maps(&before.resources, &after.resources, &included);
+    if !resource_lines.is_empty() {
+        repl_println!("~ resources");
+        for line in &resource_lines {
+            repl_println!("    {line}");
+        }
+    }
+
+    if added + removed + changed == 0 && resource_lines.is_empty() {
+        repl_println!("no differences between '{from}' and '{to}'");
+    } else {
+        repl_println!(
+            "{added} entities added, {removed} removed, {changed} changed; {} resource changes",
+            resource_lines.len()
+        );
+    }
+    Ok(())
+}
+
+/// Resolve `-f` names like `scene save -f` does, to the full type paths the
+/// snapshots are keyed by.
+fn resolve_filter(world: &World, names: &[String]) -> Result<Vec<String>, String> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    names
+        .iter()
+        .map(|name| {
+            let registration = find_registration(&registry, name)?;
+            if registration.data::<ReflectComponent>().is_none()
+                && registration.data::<ReflectResource>().is_none()
+            {
+                return Err(format!("{name} is not a reflected component or resource"));
+            }
+            Ok(registration.type_info().type_path().to_string())
+        })
+        .collect()
+}
+
+fn label(entity: Entity, state: &EntityState) -> String {
+    match &state.name {
+        Some(name) => format!("{name} ({entity})"),
+        None => entity.to_string(),
+    }
+}
+
+/// Lines for the included values added, removed or changed between two maps
+/// of values by type path, e.g. an entity's components. Lines name the values
+/// by their short type path.
+fn diff_maps(
+    before: &BTreeMap<String, Box<dyn Reflect>>,
+    after: &BTreeMap<String, Box<dyn Reflect>>,
+    included: &dyn Fn(&str) -> bool,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (path, old) in before.iter().filter(|(path, _)| included(path)) {
+        let name = old.reflect_short_type_path();
+        match after.get(path) {
+            Some(new) => diff_values(
+                name,
+                old.as_partial_reflect(),
+                new.as_partial_reflect(),
+                &mut lines,
+            ),
+            None => lines.push(format!("- {name}")),
+        }
+    }
+    for (path, new) in after.iter() {
+        if included(path) && !before.contains_key(path) {
+            lines.push(format!("+ {}", new.reflect_short_type_path()));
+        }
+    }
+    lines
+}
+
+/// Push `path: old -> new` for every part of the value that changed, going
+/// into fields until they are plain values or small groups of them like
+/// `Vec3`.
+fn diff_values(
+    path: &str,
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    lines: &mut Vec<String>,
+) {
+    if equal(old, new) {
+        return;
+    }
+    let fields: Option<Vec<(String, &dyn PartialReflect, &dyn PartialReflect)>> =
+        if only_plain_fields(old) {
+            None
+        } else {
+            match (old.reflect_ref(), new.reflect_ref()) {
+                (ReflectRef::Struct(a), ReflectRef::Struct(b)) => (0..a.field_len())
+                    .map(|i| {
+                        let name = a.name_at(i)?;
+                        Some((format!(".{name}"), a.field_at(i)?, b.field(name)?))
+                    })
+                    .collect(),
+                (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => (0..a.field_len())
+                    .map(|i| Some((format!(".{i}"), a.field(i)?, b.field(i)?)))
+                    .collect(),
+                (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => (0..a.field_len())
+                    .map(|i| Some((format!(".{i}"), a.field(i)?, b.field(i)?)))
+                    .collect(),
+                (ReflectRef::List(a), ReflectRef::List(b)) if a.len() == b.len() => (0..a.len())
+                    .map(|i| Some((format!("[{i}]"), a.get(i)?, b.get(i)?)))
+                    .collect(),
+                (ReflectRef::Array(a), ReflectRef::Array(b)) if a.len() == b.len() => (0..a.len())
+                    .map(|i| Some((format!("[{i}]"), a.get(i)?, b.get(i)?)))
+                    .collect(),
+                _ => None,
+            }
+        };
+    match fields {
+        Some(fields) => {
+            for (field, old, new) in fields {
+                diff_values(&format!("{path}{field}"), old, new, lines);
+            }
+        }
+        None => lines.push(format!(
+            "{path}: {} -> {}",
+            format_inline(old, MAX_VALUE_WIDTH),
+            format_inline(new, MAX_VALUE_WIDTH)
+        )),
+    }
+}
+
+/// Compare by `PartialEq` where reflection knows it, else by printed form.
+fn equal(old: &dyn PartialReflect, new: &dyn PartialReflect) -> bool {
+    old.reflect_partial_eq(new)
+        .unwrap_or_else(|| format!("{old:?}") == format!("{new:?}"))
+}
+
+/// Whether a value is best shown whole: a plain value, or a struct or tuple
+/// of plain values such as `Vec3` or `Quat`.
+fn only_plain_fields(value: &dyn PartialReflect) -> bool {
+    let plain = |field: &dyn PartialReflect| matches!(field.reflect_ref(), ReflectRef::Opaque(_));
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => s.iter_fields().all(plain),
+        ReflectRef::TupleStruct(s) => s.iter_fields().all(plain),
+        ReflectRef::Tuple(t) => t.iter_fields().all(plain),
+        ReflectRef::List(_) | ReflectRef::Array(_) => false,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod snapshot_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+    use bevy::reflect::TypePath;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health {
+        hp: f32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Stats {
+        level: u32,
+        inner: Inner,
+    }
+
+    #[derive(Reflect, Default)]
+    struct Inner {
+        xp: u32,
+    }
+
+    /// Two components that share the short name `Score`.
+    mod a {
+        use bevy::prelude::*;
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        pub struct Score(pub u32);
+    }
+
+    mod b {
+        use bevy::prelude::*;
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        pub struct Score(pub u32);
+    }
+
+    fn damage(mut query: Query<&mut Health>) {
+        for mut health in &mut query {
+            health.hp -= 1.0;
+        }
+    }
+
+    fn setup(app: &mut App) {
+        app.register_type::<Health>()
+            .register_type::<Stats>()
+            .register_type::<a::Score>()
+            .register_type::<b::Score>()
+            .add_systems(Update, damage);
+    }
+
+    #[test]
+    fn test_snapshot_diff_between_frames() {
+        let mut app = app((plugin, setup));
+        let world = app.world_mut();
+        let player = world.spawn((Name::new("Player"), Health { hp: 10.0 })).id();
+        let enemy = world.spawn((Name::new("Enemy"), Health { hp: 3.0 })).id();
+        run_ok(&mut app, "snapshot take before");
+
+        app.update();
+        app.world_mut().despawn(enemy);
+        let bullet = app
+            .world_mut()
+            .spawn((Name::new("Bullet"), Health { hp: 1.0 }))
+            .id();
+        run_ok(&mut app, "snapshot take after");
+
+        let mut entities = vec![
+            (
+                player,
+                vec![
+                    format!("~ Player ({player})"),
+                    "    Health: Health { hp: 10.0 } -> Health { hp: 9.0 }".to_string(),
+                ],
+            ),
+            (enemy, vec![format!("- Enemy ({enemy})")]),
+            (bullet, vec![format!("+ Bullet ({bullet})")]),
+        ];
+        entities.sort_by_key(|(entity, _)| (entity.index_u32(), entity.generation().to_bits()));
+        let mut expected: Vec<String> = entities.into_iter().flat_map(|(_, lines)| lines).collect();
+        expected.push("1 entities added, 1 removed, 1 changed; 0 resource changes".to_string());
+        assert_eq!(
+            run_ok(&mut app, "snapshot diff before after -f Health"),
+            expected
+        );
+        assert_eq!(
+            run_ok(&mut app, "snapshot diff after now -f Health"),
+            vec!["no differences between 'after' and 'now'"]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_diff_nested_fields() {
+        let mut app = app((plugin, setup));
+        let entity = app.world_mut().spawn(Stats::default()).id();
+        run_ok(&mut app, "snapshot take before");
+        let mut stats = app.world_mut().get_mut::<Stats>(entity).unwrap();
+        stats.level = 2;
+        stats.inner.xp = 40;
+        let lines = run_ok(&mut app, "snapshot diff before now -f Stats");
+        assert_eq!(
+            lines[..3],
+            [
+                format!("~ {entity}"),
+                "    Stats.level: 0 -> 2".to_string(),
+                "    Stats.inner: Inner { xp: 0 } -> Inner { xp: 40 }".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_snapshot_keeps_types_with_the_same_short_name_apart() {
+        let mut app = app((plugin, setup));
+        let entity = app.world_mut().spawn((a::Score(1), b::Score(1))).id();
+        run_ok(&mut app, "snapshot take before");
+        app.world_mut().get_mut::<b::Score>(entity).unwrap().0 = 2;
+
+        let lines = run_ok(&mut app, "snapshot diff before now");
+        assert!(lines.contains(&"    Score: Score(1) -> Score(2)".to_string()));
+        let lines = run_ok(
+            &mut app,
+            &format!("snapshot diff before now -f {}", a::Score::type_path()),
+        );
+        assert_eq!(lines, vec!["no differences between 'before' and 'now'"]);
+        let lines = run_ok(
+            &mut app,
+            &format!("snapshot diff before now -f {}", b::Score::type_path()),
+        );
+        assert_eq!(lines[1], "    Score: Score(1) -> Score(2)");
+    }
+
+    #[test]
+    fn test_snapshot_filter_must_name_a_known_type() {
+        let mut app = app((plugin, setup));
+        run_ok(&mut app, "snapshot take before");
+        for filter in ["Mana", "health", "Score", "Inner"] {
+            let (outcome, _) = run(&mut app, &format!("snapshot diff before now -f {filter}"));
+            assert!(matches!(outcome, CommandOutcome::Failed(_)), "{filter}");
+        }
+    }
+
+    #[test]
+    fn test_snapshot_take_list_drop() {
+        let mut app = app((plugin, setup));
+        let (outcome, _) = run(&mut app, "snapshot take now");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        run_ok(&mut app, "snapshot take before");
+        let lines = run_ok(&mut app, "snapshot list");
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].starts_with("before  "));
+        assert_eq!(
+            run_ok(&mut app, "snapshot drop before"),
+            vec!["dropped snapshot 'before'"]
+        );
+        let (outcome, _) = run(&mut app, "snapshot diff before now");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::command::{parse_entity_selector, EntitySelector, ReplCommandTracker};
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistry;
+use bevy::scene::serde::SceneMapDeserializer;
+use serde::de::DeserializeSeed;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<SpawnCommand>();
+    app.add_repl_command::<DespawnCommand>();
+    app.add_observer(on_spawn);
+    app.add_observer(on_despawn);
+}
+
+#[derive(Event, Clone, Default)]
+struct SpawnCommand {
+    components: String,
+}
+
+impl crate::command::ReplCommand for SpawnCommand {
+    fn clap_command() -> clap::Command {
+        clap::Command::new("spawn")
+            .about("Spawn an entity from reflected components in RON")
+            .arg(
+                clap::Arg::new("components")
+                    .num_args(0..)
+                    .allow_hyphen_values(true)
+                    .help("A RON map of component type paths to values"),
+            )
+            .after_help(
+                "Components are keyed by their full type path, as in a scene file.\n\
+                 Example: spawn '{ \"bevy_ecs::name::Name\": \"Bob\" }'",
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            components: matches
+                .get_many::<String>("components")
+                .map(|v| v.cloned().collect::<Vec<_>>().join(" "))
+                .unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Event, Clone, Default)]
+struct DespawnCommand {
+    entity: EntitySelector,
+    recursive: bool,
+}
+
+impl crate::command::ReplCommand for DespawnCommand {
+    fn clap_command() -> clap::Command {
+        clap::Command::new("despawn")
+            .about("Despawn entities by ID, name or name glob")
+            .arg(
+                clap::Arg::new("entity")
+                    .required(true)
+                    .value_parser(parse_entity_selector)
+                    .help("Entity ID (e.g. 12v1), Name, or glob such as 'Enemy*'"),
+            )
+            .arg(
+                clap::Arg::new("recursive")
+                    .short('r')
+                    .long("recursive")
+                    .action(clap::ArgAction::SetTrue)
+                    .help("Also despawn children, instead of leaving them parentless"),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            entity: matches
+                .get_one::<EntitySelector>("entity")
+                .cloned()
+                .unwrap_or_default(),
+            recursive: matches.get_flag("recursive"),
+        })
+    }
+}
+
+fn on_spawn(trigger: On<SpawnCommand>, mut commands: Commands) {
+    let input = trigger.event().components.clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = spawn_from_ron(world, &input) {
+            repl_println!("spawn: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn spawn_from_ron(world: &mut World, input: &str) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    // Parse everything before spawning so a bad value doesn't leave a
+    // half-built entity behind
+    let components = if input.trim().is_empty() {
+        Vec::new()
+    } else {
+        parse_components(&registry, input).map_err(|e| {
+            if input.contains('"') {
+                e
+            } else {
+                // The shell-style tokenizer ate the quotes around the keys
+                format!("{e} (wrap the map in single quotes)")
+            }
+        })?
+    };
+    let mut inserts = Vec::new();
+    for value in &components {
+        let type_path = value.reflect_type_path();
+        let reflect = value
+            .get_represented_type_info()
+            .and_then(|info| registry.get(info.type_id()))
+            .and_then(|registration| registration.data::<ReflectComponent>())
+            .ok_or_else(|| format!("{type_path} is not a reflected component"))?;
+        inserts.push((reflect, value));
+    }
+    let mut entity = world.spawn_empty();
+    for (reflect, value) in inserts {
+        reflect.insert(&mut entity, value.as_partial_reflect(), &registry);
+    }
+    repl_println!("spawned {}", entity.id());
+    Ok(())
+}
+
+/// Parse a RON map of components keyed by their full type path, the same
+/// format as the components of an entity in a `.scn.ron` file:
+///
+/// ```text
+/// { "bevy_ecs::name::Name": "Bob", "my_game::Health": (current: 100) }
+/// ```
+fn parse_components(
+    registry: &TypeRegistry,
+    input: &str,
+) -> Result<Vec<Box<dyn PartialReflect>>, String> {
+    let mut ron = ron::Deserializer::from_str(input).map_err(|e| e.to_string())?;
+    SceneMapDeserializer { registry }
+        .deserialize(&mut ron)
+        .and_then(|components| ron.end().map(|_| components))
+        .map_err(|e| ron.span_error(e).to_string())
+}
+
+fn on_despawn(trigger: On<DespawnCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        let entities = cmd.entity.resolve(world);
+        if entities.is_empty() {
+            let error = format!("no entity matches {}", cmd.entity);
+            repl_println!("despawn: {error}");
+            world.resource_mut::<ReplCommandTracker>().fail(error);
+            return;
+        }
+        for entity in entities {
+            // A glob may match both a parent and its children
+            let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+                continue;
+            };
+            if !cmd.recursive {
+                // Removing `Children` detaches the children so they survive
+                entity_mut.remove::<Children>();
+            }
+            entity_mut.despawn();
+            repl_println!("despawned {entity}");
+        }
+    });
+}
+
+#[cfg(test)]
+mod spawn_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+    use bevy::reflect::TypePath;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Health {
+        hp: f32,
+    }
+
+    fn setup(app: &mut App) {
+        app.register_type::<Health>().register_type::<Name>();
+    }
+
+    fn count<C: Component>(app: &mut App) -> usize {
+        let world = app.world_mut();
+        world.query::<&C>().iter(world).count()
+    }
+
+    #[test]
+    fn test_spawn_from_ron_map() {
+        let mut app = app((plugin, setup));
+        let input = format!(
+            r#"spawn '{{ "{}": "Bob", "{}": (hp: 3.0) }}'"#,
+            Name::type_path(),
+            Health::type_path()
+        );
+        let lines = run_ok(&mut app, &input);
+        assert!(lines[0].starts_with("spawned "));
+        let world = app.world_mut();
+        let (name, health) = world.query::<(&Name, &Health)>().single(world).unwrap();
+        assert_eq!(name.as_str(), "Bob");
+        assert_eq!(health.hp, 3.0);
+    }
+
+    #[test]
+    fn test_spawn_bad_value_spawns_nothing() {
+        let mut app = app((plugin, setup));
+        let before = app.world().entities().len();
+        let input = format!(
+            r#"spawn '{{ "{}": "Bob", "{}": (hp: "x") }}'"#,
+            Name::type_path(),
+            Health::type_path()
+        );
+        // Keys are full type paths, as in a scene file
+        for input in [input.as_str(), r#"spawn '{ "Health": (hp: 3.0) }'"#] {
+            let (outcome, _) = run(&mut app, input);
+            assert!(matches!(outcome, CommandOutcome::Failed(_)), "{input}");
+        }
+        assert_eq!(app.world().entities().len(), before);
+    }
+
+    #[test]
+    fn test_despawn_keeps_children_unless_recursive() {
+        let mut app = app((plugin, setup));
+        let world = app.world_mut();
+        let parent = world.spawn(Name::new("Parent")).id();
+        let child = world.spawn((Name::new("Child"), ChildOf(parent))).id();
+        run_ok(&mut app, "despawn Parent");
+        assert!(app.world().get_entity(parent).is_err());
+        assert!(app.world().get::<ChildOf>(child).is_none());
+
+        let world = app.world_mut();
+        let parent = world.spawn(Name::new("Parent")).id();
+        world.entity_mut(child).insert(ChildOf(parent));
+        run_ok(&mut app, "despawn -r Parent");
+        assert_eq!(count::<Name>(&mut app), 0);
+    }
+
+    #[test]
+    fn test_despawn_unknown_fails() {
+        let mut app = app((plugin, setup));
+        let (outcome, _) = run(&mut app, "despawn Nobody");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+}
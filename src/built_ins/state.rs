@@ -0,0 +1,220 @@
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::reflection::{find_registration, format_inline, parse_ron};
+use crate::repl_println;
+use bevy::ecs::reflect::ReflectResource;
+use bevy::prelude::*;
+use bevy::reflect::{ReflectFromReflect, ReflectRef, TypeRegistration, TypeRegistry};
+use bevy::state::reflect::{ReflectFreelyMutableState, ReflectState};
+use std::any::TypeId;
+
+/// Widest a state value may get in `state list` before it is cut.
+const MAX_VALUE_WIDTH: usize = 64;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<StateCommand>();
+    app.add_observer(on_state);
+}
+
+#[derive(Event, Clone, Default)]
+enum StateCommand {
+    /// List states with their current and pending values.
+    #[default]
+    List,
+    /// Queue a transition to a new state value.
+    Set { state: String, value: String },
+}
+
+impl crate::command::ReplCommand for StateCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{Arg, Command};
+
+        Command::new("state")
+            .about("List and change reflected Bevy states")
+            .subcommand(Command::new("list").about("List states and their current values"))
+            .subcommand(
+                Command::new("set")
+                    .about("Queue a transition to another state")
+                    .arg(
+                        Arg::new("state")
+                            .required(true)
+                            .help("State type, e.g. GameState"),
+                    )
+                    .arg(
+                        Arg::new("value")
+                            .required(true)
+                            .num_args(1..)
+                            .allow_hyphen_values(true)
+                            .help("Variant name or RON value, e.g. InMenu"),
+                    ),
+            )
+            .after_help(
+                "States must be registered with app.register_type_state::<S>(), or \
+                 app.register_type_mutable_state::<S>() to be settable.",
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(match matches.subcommand() {
+            Some(("set", m)) => Self::Set {
+                state: m.get_one::<String>("state").cloned().unwrap_or_default(),
+                value: m
+                    .get_many::<String>("value")
+                    .map(|v| v.cloned().collect::<Vec<_>>().join(" "))
+                    .unwrap_or_default(),
+            },
+            _ => Self::List,
+        })
+    }
+}
+
+fn on_state(trigger: On<StateCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        let result = match &cmd {
+            StateCommand::List => {
+                list_states(world);
+                Ok(())
+            }
+            StateCommand::Set { state, value } => set_state(world, state, value),
+        };
+        if let Err(e) = result {
+            repl_println!("state: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn list_states(world: &World) {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut states: Vec<(&str, String)> = registry
+        .iter()
+        .filter_map(|registration| {
+            let reflect = registration.data::<ReflectState>()?;
+            let name = registration.type_info().type_path_table().short_path();
+            let Some(current) = reflect.reflect(world) else {
+                return Some((name, "<not initialized>".to_string()));
+            };
+            let mut line = format_inline(current.as_partial_reflect(), MAX_VALUE_WIDTH);
+            if let Some(next) = pending_state(world, &registry, registration.type_id()) {
+                line.push_str(&format!(" -> {next} (pending)"));
+            }
+            Some((name, line))
+        })
+        .collect();
+    if states.is_empty() {
+        repl_println!("no reflected states (register them with app.register_type_state)");
+        return;
+    }
+    states.sort();
+    for (name, line) in states {
+        repl_println!("{name} = {line}");
+    }
+}
+
+/// The value waiting in `NextState<S>`, if a transition is queued.
+fn pending_state(world: &World, registry: &TypeRegistry, state: TypeId) -> Option<String> {
+    let next_state = registry.iter().find(|registration| {
+        let info = registration.type_info();
+        info.type_path_table().ident() == Some("NextState")
+            && info
+                .generics()
+                .get_named("S")
+                .is_some_and(|param| param.type_id() == state)
+    })?;
+    let next = next_state.data::<ReflectResource>()?.reflect(world).ok()?;
+    // `NextState::Unchanged` has no field; both pending variants have one
+    let ReflectRef::Enum(next) = next.reflect_ref() else {
+        return None;
+    };
+    next.field_at(0)
+        .map(|value| format_inline(value, MAX_VALUE_WIDTH))
+}
+
+fn set_state(world: &mut World, state: &str, value: &str) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let registration = find_state(&registry, state)?;
+    let name = registration.type_info().type_path_table().short_path();
+    let Some(mutable) = registration.data::<ReflectFreelyMutableState>() else {
+        return Err(format!(
+            "{name} can't be set directly (computed states, or not registered with register_type_mutable_state)"
+        ));
+    };
+    let reflect = registration.data::<ReflectState>();
+    if reflect.and_then(|reflect| reflect.reflect(world)).is_none() {
+        return Err(format!("{name} has not been initialized"));
+    }
+
+    let value = parse_ron(&registry, registration, value)?;
+    let value = registration
+        .data::<ReflectFromReflect>()
+        .and_then(|from_reflect| from_reflect.from_reflect(value.as_ref()))
+        .or_else(|| value.try_into_reflect().ok())
+        .ok_or_else(|| format!("could not build a {name} from that value"))?;
+    mutable.set_next_state(world, value.as_ref(), &registry);
+    repl_println!(
+        "{name} -> {} (applied at the next StateTransition)",
+        format_inline(value.as_partial_reflect(), MAX_VALUE_WIDTH)
+    );
+    Ok(())
+}
+
+fn find_state<'r>(registry: &'r TypeRegistry, name: &str) -> Result<&'r TypeRegistration, String> {
+    let registration = find_registration(registry, name)?;
+    if registration.data::<ReflectState>().is_none() {
+        return Err(format!(
+            "{name} is not a registered state (use app.register_type_state::<{name}>())"
+        ));
+    }
+    Ok(registration)
+}
+
+#[cfg(test)]
+mod state_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+    use bevy::state::app::StatesPlugin;
+
+    #[derive(States, Reflect, Default, Debug, Clone, PartialEq, Eq, Hash)]
+    enum GameState {
+        #[default]
+        Menu,
+        Playing,
+    }
+
+    fn setup(app: &mut App) {
+        app.init_state::<GameState>()
+            .register_type_mutable_state::<GameState>();
+    }
+
+    #[test]
+    fn test_state_set_queues_transition() {
+        let mut app = app((StatesPlugin, plugin, setup));
+        assert_eq!(run_ok(&mut app, "state list"), vec!["GameState = Menu"]);
+        run_ok(&mut app, "state set GameState Playing");
+        assert_eq!(
+            run_ok(&mut app, "state list"),
+            vec!["GameState = Menu -> Playing (pending)"]
+        );
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Playing
+        );
+    }
+
+    #[test]
+    fn test_state_set_rejects_unknown() {
+        let mut app = app((StatesPlugin, plugin, setup));
+        let (outcome, _) = run(&mut app, "state set GameState Paused");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        let (outcome, _) = run(&mut app, "state set Weather Rain");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Menu
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use crate::command::{
+    dispatch_command, CommandHandle, CommandOutcome, ReplCommandFinished, ReplCommandTracker,
+};
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<TimeCommand>();
+    app.add_observer(on_time);
+    app.init_resource::<PendingTimings>();
+    app.add_systems(Last, report_timings);
+}
+
+#[derive(Event, Clone, Default)]
+struct TimeCommand {
+    command: Vec<String>,
+}
+
+impl crate::command::ReplCommand for TimeCommand {
+    fn clap_command() -> clap::Command {
+        clap::Command::new("time")
+            .about("Runs a command and reports how long it took to finish")
+            .arg(
+                clap::Arg::new("command")
+                    .help("The command line to run")
+                    .required(true)
+                    .num_args(1..)
+                    .trailing_var_arg(true)
+                    .allow_hyphen_values(true),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> crate::command::ReplResult<Self> {
+        Ok(Self {
+            command: matches
+                .get_many::<String>("command")
+                .map(|args| args.cloned().collect())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Timed commands, keyed by the handle of the inner command, with the handle
+/// of the `time` command that started them.
+#[derive(Resource, Default)]
+struct PendingTimings(HashMap<CommandHandle, CommandHandle>);
+
+fn on_time(
+    trigger: On<TimeCommand>,
+    mut tracker: ResMut<ReplCommandTracker>,
+    mut commands: Commands,
+) {
+    // `time` itself finishes when the timed command does
+    let Some(parent) = tracker.defer() else {
+        return;
+    };
+    let line = shell_words::join(&trigger.event().command);
+    commands.queue(
+        move |world: &mut World| match dispatch_command(world, &line) {
+            Some(inner) => {
+                world
+                    .resource_mut::<PendingTimings>()
+                    .0
+                    .insert(inner, parent);
+            }
+            None => {
+                world
+                    .resource_mut::<ReplCommandTracker>()
+                    .complete(parent, CommandOutcome::Failed("nothing to time".into()));
+            }
+        },
+    );
+}
+
+fn report_timings(
+    mut finished: MessageReader<ReplCommandFinished>,
+    mut pending: ResMut<PendingTimings>,
+    mut tracker: ResMut<ReplCommandTracker>,
+) {
+    for done in finished.read() {
+        let Some(parent) = pending.0.remove(&done.handle) else {
+            continue;
+        };
+        let frames = if done.frames == 1 { "frame" } else { "frames" };
+        repl_println!(
+            "time: {:.3?} real, {} {} ({})",
+            done.elapsed,
+            done.frames,
+            frames,
+            done.outcome
+        );
+        tracker.complete(parent, done.outcome.clone());
+    }
+}
@@ -0,0 +1,279 @@
+use super::NotInternal;
+use crate::command::{parse_entity_selector, EntitySelector, ReplCommandTracker};
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::prelude::*;
+
+/// Default for `--depth`.
+const DEFAULT_DEPTH: usize = 8;
+/// Default for `--items`.
+const DEFAULT_ITEMS: usize = 32;
+
+/// Entities without a parent. Observers and registered systems are entities
+/// too, but not part of any hierarchy worth showing.
+type TopLevel = (Without<ChildOf>, NotInternal);
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<TreeCommand>();
+    app.add_observer(on_tree);
+}
+
+#[derive(Event, Clone)]
+struct TreeCommand {
+    root: Option<EntitySelector>,
+    /// Children deeper than this are collapsed.
+    max_depth: usize,
+    /// Children past this many are summed up on one line.
+    max_items: usize,
+    components: bool,
+}
+
+impl Default for TreeCommand {
+    fn default() -> Self {
+        Self {
+            root: None,
+            max_depth: DEFAULT_DEPTH,
+            max_items: DEFAULT_ITEMS,
+            components: false,
+        }
+    }
+}
+
+impl crate::command::ReplCommand for TreeCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{value_parser, Arg, ArgAction, Command};
+
+        Command::new("tree")
+            .about("Print the entity hierarchy (ChildOf/Children) as an indented tree")
+            .arg(Arg::new("root").value_parser(parse_entity_selector).help(
+                "Entity ID (e.g. 12v1) or Name to start at; all top-level entities if omitted",
+            ))
+            .arg(
+                Arg::new("depth")
+                    .short('d')
+                    .long("depth")
+                    .value_parser(value_parser!(usize))
+                    .help(format!(
+                        "How many levels of children to expand [default: {}]",
+                        DEFAULT_DEPTH
+                    )),
+            )
+            .arg(
+                Arg::new("items")
+                    .short('n')
+                    .long("items")
+                    .value_parser(value_parser!(usize))
+                    .help(format!(
+                        "How many children of each entity to print [default: {}]",
+                        DEFAULT_ITEMS
+                    )),
+            )
+            .arg(
+                Arg::new("components")
+                    .short('c')
+                    .long("components")
+                    .action(ArgAction::SetTrue)
+                    .help("List each entity's components"),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            root: matches.get_one::<EntitySelector>("root").cloned(),
+            max_depth: matches
+                .get_one::<usize>("depth")
+                .copied()
+                .unwrap_or(DEFAULT_DEPTH),
+            max_items: matches
+                .get_one::<usize>("items")
+                .copied()
+                .unwrap_or(DEFAULT_ITEMS),
+            components: matches.get_flag("components"),
+        })
+    }
+}
+
+fn on_tree(trigger: On<TreeCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        let mut roots = match &cmd.root {
+            Some(selector) => selector.resolve(world),
+            None => world
+                .query_filtered::<Entity, TopLevel>()
+                .iter(world)
+                .collect(),
+        };
+        if roots.is_empty() {
+            let error = match &cmd.root {
+                Some(selector) => format!("no entity matches {selector}"),
+                None => "the world has no entities".to_string(),
+            };
+            repl_println!("tree: {error}");
+            world.resource_mut::<ReplCommandTracker>().fail(error);
+            return;
+        }
+        roots.sort_by_key(|entity| (entity.index_u32(), entity.generation().to_bits()));
+
+        let mut lines = Vec::new();
+        let shown = roots.len().min(cmd.max_items);
+        for &root in &roots[..shown] {
+            write_entity(world, &mut lines, root, ("", ""), 0, &cmd);
+        }
+        if roots.len() > shown {
+            lines.push(format!(
+                "... {} more top-level entities",
+                roots.len() - shown
+            ));
+        }
+        for line in lines {
+            repl_println!("{line}");
+        }
+    });
+}
+
+/// Write one line for `entity`, then its children below it. `prefix` holds
+/// the guide lines for the entity's own line and for the lines under it.
+fn write_entity(
+    world: &World,
+    lines: &mut Vec<String>,
+    entity: Entity,
+    prefix: (&str, &str),
+    depth: usize,
+    cmd: &TreeCommand,
+) {
+    let (first, rest) = prefix;
+    let mut line = format!("{first}{}", label(world, entity, cmd));
+    let Some(children) = world.get::<Children>(entity) else {
+        lines.push(line);
+        return;
+    };
+    if depth >= cmd.max_depth {
+        // Collapsed: only say how much is hidden
+        line.push_str(&format!(" [+{}]", descendant_count(world, entity)));
+        lines.push(line);
+        return;
+    }
+    lines.push(line);
+
+    let shown = children.len().min(cmd.max_items);
+    let hidden = children.len() - shown;
+    for (i, &child) in children[..shown].iter().enumerate() {
+        let (branch, guide) = if i + 1 == shown && hidden == 0 {
+            ("└─ ", "   ")
+        } else {
+            ("├─ ", "│  ")
+        };
+        write_entity(
+            world,
+            lines,
+            child,
+            (&format!("{rest}{branch}"), &format!("{rest}{guide}")),
+            depth + 1,
+            cmd,
+        );
+    }
+    if hidden > 0 {
+        let below: usize = children[shown..]
+            .iter()
+            .map(|&child| 1 + descendant_count(world, child))
+            .sum();
+        lines.push(format!(
+            "{rest}└─ ... {hidden} more children ({below} entities)"
+        ));
+    }
+}
+
+/// `Name (id)`, followed by the component names with `--components`.
+fn label(world: &World, entity: Entity, cmd: &TreeCommand) -> String {
+    let mut label = match world.get::<Name>(entity) {
+        Some(name) => format!("{name} ({entity})"),
+        None => entity.to_string(),
+    };
+    if cmd.components {
+        // The hierarchy and names are already shown by the tree itself
+        let names: Vec<String> = world
+            .entity(entity)
+            .archetype()
+            .components()
+            .iter()
+            .filter_map(|&id| world.components().get_info(id))
+            .map(|info| info.name().shortname().to_string())
+            .filter(|name| !matches!(name.as_str(), "Name" | "ChildOf" | "Children"))
+            .collect();
+        if !names.is_empty() {
+            label.push_str(&format!("  [{}]", names.join(", ")));
+        }
+    }
+    label
+}
+
+fn descendant_count(world: &World, entity: Entity) -> usize {
+    world.get::<Children>(entity).map_or(0, |children| {
+        children
+            .iter()
+            .map(|child| 1 + descendant_count(world, child))
+            .sum()
+    })
+}
+
+#[cfg(test)]
+mod tree_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::command::CommandOutcome;
+
+    #[derive(Component)]
+    struct Marker;
+
+    /// `Root` with children `A` (which has `A1`) and `B`.
+    fn tree_app() -> (App, [Entity; 4]) {
+        let mut app = app(plugin);
+        let world = app.world_mut();
+        let root = world.spawn((Name::new("Root"), Marker)).id();
+        let a = world.spawn((Name::new("A"), ChildOf(root))).id();
+        let a1 = world.spawn((Name::new("A1"), ChildOf(a))).id();
+        let b = world.spawn((Name::new("B"), ChildOf(root))).id();
+        (app, [root, a, a1, b])
+    }
+
+    #[test]
+    fn test_tree_prints_hierarchy() {
+        let (mut app, [root, a, a1, b]) = tree_app();
+        let expected = vec![
+            format!("Root ({root})"),
+            format!("├─ A ({a})"),
+            format!("│  └─ A1 ({a1})"),
+            format!("└─ B ({b})"),
+        ];
+        assert_eq!(run_ok(&mut app, "tree Root"), expected);
+        // Observers and registered systems aren't top-level entities
+        assert_eq!(run_ok(&mut app, "tree"), expected);
+    }
+
+    #[test]
+    fn test_tree_limits() {
+        let (mut app, [root, a, a1, _]) = tree_app();
+        assert_eq!(
+            run_ok(&mut app, "tree Root --depth 0"),
+            vec![format!("Root ({root}) [+3]")]
+        );
+        assert_eq!(
+            run_ok(&mut app, "tree Root --items 1"),
+            vec![
+                format!("Root ({root})"),
+                format!("├─ A ({a})"),
+                format!("│  └─ A1 ({a1})"),
+                "└─ ... 1 more children (1 entities)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tree_components() {
+        let (mut app, [root, ..]) = tree_app();
+        let lines = run_ok(&mut app, "tree Root -c -d 0");
+        assert_eq!(lines, vec![format!("Root ({root})  [Marker] [+3]")]);
+        let (outcome, _) = run(&mut app, "tree Nobody");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+}
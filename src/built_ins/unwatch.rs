@@ -0,0 +1,58 @@
+//! `unwatch`, shared by `watch` and `watch-messages`. Each of them observes
+//! [`UnwatchCommand`], stops its matching watches and adds them to
+//! [`StoppedWatches`]; this module reports the total.
+
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::repl_println;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<UnwatchCommand>();
+    app.init_resource::<StoppedWatches>();
+    app.add_observer(on_unwatch);
+}
+
+#[derive(Event, Clone, Default)]
+pub(super) struct UnwatchCommand {
+    /// What to stop watching; everything if `None`.
+    pub target: Option<String>,
+}
+
+impl crate::command::ReplCommand for UnwatchCommand {
+    fn clap_command() -> clap::Command {
+        clap::Command::new("unwatch")
+            .about("Stop watching commands or messages")
+            .arg(
+                clap::Arg::new("target")
+                    .help("Command or message type to stop watching; everything if omitted"),
+            )
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            target: matches.get_one::<String>("target").cloned(),
+        })
+    }
+}
+
+/// Watches stopped by the `unwatch` being handled.
+#[derive(Resource, Default)]
+pub(super) struct StoppedWatches(pub usize);
+
+fn on_unwatch(_trigger: On<UnwatchCommand>, mut commands: Commands) {
+    // Queued, so it runs after every observer of this `unwatch` has counted
+    commands.queue(|world: &mut World| {
+        let stopped = std::mem::take(&mut world.resource_mut::<StoppedWatches>().0);
+        if stopped == 0 {
+            let error = "nothing to unwatch".to_string();
+            repl_println!("unwatch: {error}");
+            world.resource_mut::<ReplCommandTracker>().fail(error);
+        } else {
+            repl_println!(
+                "stopped {stopped} watch{}",
+                if stopped == 1 { "" } else { "es" }
+            );
+        }
+    });
+}
@@ -0,0 +1,311 @@
+use std::time::{Duration, Instant};
+
+use super::unwatch::{StoppedWatches, UnwatchCommand};
+use crate::command::{
+    dispatch_command_from, parse_duration, CommandHandle, CommandSource, ReplCommandTracker,
+};
+use crate::prelude::*;
+use crate::print::capture_output;
+use crate::repl_println;
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<WatchCommand>();
+    app.add_observer(on_watch);
+    app.add_observer(on_unwatch);
+    app.init_resource::<CommandWatch>();
+    // Also present without the prompt plugins, e.g. in headless apps
+    app.init_resource::<PinnedLines>();
+    app.add_systems(Last, (stop_on_clear_key, run_command_watch).chain());
+}
+
+#[derive(Event, Clone, Default)]
+struct WatchCommand {
+    interval: Option<Duration>,
+    frames: Option<u32>,
+    command: Vec<String>,
+}
+
+impl crate::command::ReplCommand for WatchCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{builder::RangedU64ValueParser, Arg, Command};
+
+        Command::new("watch")
+            .about("Re-run a command on an interval, showing its output above the prompt")
+            .arg(
+                Arg::new("interval")
+                    .short('n')
+                    .long("interval")
+                    .value_parser(parse_duration)
+                    .conflicts_with("frames")
+                    .help("Real time between runs, in seconds or e.g. 250ms [default: 2s]"),
+            )
+            .arg(
+                Arg::new("frames")
+                    .long("frames")
+                    .value_parser(RangedU64ValueParser::<u32>::new().range(1..))
+                    .help("Run every N frames instead of on a timer"),
+            )
+            .arg(
+                Arg::new("command")
+                    .help("The command line to run")
+                    .required(true)
+                    .num_args(1..)
+                    .trailing_var_arg(true)
+                    .allow_hyphen_values(true),
+            )
+            .after_help("Stop with Esc (the prompt's clear key) or 'unwatch'.")
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            interval: matches.get_one::<Duration>("interval").copied(),
+            frames: matches.get_one::<u32>("frames").copied(),
+            command: matches
+                .get_many::<String>("command")
+                .map(|args| args.cloned().collect())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Interval {
+    Real(Duration),
+    Frames(u32),
+}
+
+struct Watch {
+    line: String,
+    /// The command's name, which `unwatch` also accepts.
+    name: String,
+    interval: Interval,
+    last_run: Instant,
+    frames_since_run: u32,
+    /// The last run, if it hasn't finished yet. Runs don't overlap.
+    running: Option<CommandHandle>,
+}
+
+/// The watched command. There is one output area, so one watch at a time.
+#[derive(Resource, Default)]
+struct CommandWatch(Option<Watch>);
+
+fn on_watch(trigger: On<WatchCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = start_watch(world, cmd) {
+            repl_println!("watch: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn start_watch(world: &mut World, cmd: WatchCommand) -> Result<(), String> {
+    let name = cmd.command.first().cloned().unwrap_or_default();
+    if name == "watch" {
+        return Err("can't watch 'watch'".to_string());
+    }
+    if !world.resource::<Repl>().commands.contains_key(&name) {
+        return Err(format!("unknown command '{name}'"));
+    }
+    let interval = match (cmd.frames, cmd.interval) {
+        (Some(frames), _) => Interval::Frames(frames),
+        (None, Some(interval)) if interval.is_zero() => {
+            return Err("interval must be greater than zero".to_string())
+        }
+        (None, interval) => Interval::Real(interval.unwrap_or(Duration::from_secs(2))),
+    };
+    let mut watch = Watch {
+        line: shell_words::join(&cmd.command),
+        name,
+        interval,
+        last_run: Instant::now(),
+        frames_since_run: 0,
+        running: None,
+    };
+    // Show the first output right away instead of after one interval
+    run_once(world, &mut watch);
+    world.resource_mut::<CommandWatch>().0 = Some(watch);
+    Ok(())
+}
+
+fn on_unwatch(
+    trigger: On<UnwatchCommand>,
+    mut watch: ResMut<CommandWatch>,
+    mut pinned: ResMut<PinnedLines>,
+    mut stopped: ResMut<StoppedWatches>,
+) {
+    let matches = watch.0.as_ref().is_some_and(|watch| {
+        trigger
+            .event()
+            .target
+            .as_ref()
+            .is_none_or(|target| *target == watch.name || *target == watch.line)
+    });
+    if matches {
+        watch.0 = None;
+        pinned.0.clear();
+        stopped.0 += 1;
+    }
+}
+
+fn stop_on_clear_key(
+    mut events: MessageReader<ReplBufferEvent>,
+    mut watch: ResMut<CommandWatch>,
+    mut pinned: ResMut<PinnedLines>,
+) {
+    if !events
+        .read()
+        .any(|event| matches!(event, ReplBufferEvent::Clear))
+    {
+        return;
+    }
+    if let Some(stopped) = watch.0.take() {
+        pinned.0.clear();
+        repl_println!("stopped watching '{}'", stopped.line);
+    }
+}
+
+fn run_command_watch(world: &mut World) {
+    let Some(mut watch) = world.resource_mut::<CommandWatch>().0.take() else {
+        return;
+    };
+    watch.frames_since_run += 1;
+    let due = match watch.interval {
+        Interval::Real(interval) => watch.last_run.elapsed() >= interval,
+        Interval::Frames(frames) => watch.frames_since_run >= frames,
+    };
+    let idle = watch
+        .running
+        .is_none_or(|handle| !world.resource::<ReplCommandTracker>().is_running(handle));
+    if due && idle {
+        run_once(world, &mut watch);
+    }
+    world.resource_mut::<CommandWatch>().0 = Some(watch);
+}
+
+/// Run the watched command and pin its output above the prompt. Runs are
+/// [`CommandSource::Watch`], so they don't flood the audit log or other
+/// [`ReplCommandFinished`](crate::command::ReplCommandFinished) readers.
+fn run_once(world: &mut World, watch: &mut Watch) {
+    let (handle, output) =
+        capture_output(|| dispatch_command_from(world, &watch.line, CommandSource::Watch));
+    watch.running = handle;
+    watch.last_run = Instant::now();
+    watch.frames_since_run = 0;
+
+    let every = match watch.interval {
+        Interval::Real(interval) => format!("{interval:?}"),
+        Interval::Frames(1) => "frame".to_string(),
+        Interval::Frames(frames) => format!("{frames} frames"),
+    };
+    let mut lines = vec![format!(
+        "every {every}: {}  (Esc or 'unwatch' to stop)",
+        watch.line
+    )];
+    lines.extend(output.iter().map(|line| strip_ansi(line)));
+    world.resource_mut::<PinnedLines>().0 = lines;
+}
+
+/// Drop terminal escape sequences (e.g. clap's error colors), which would be
+/// drawn as text in the pinned area.
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        // CSI sequences end with a byte in '@'..='~'
+        if chars.next() == Some('[') {
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod watch_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::built_ins::unwatch;
+    use crate::command::CommandOutcome;
+
+    #[derive(Event, Clone, Default)]
+    struct PingCommand;
+
+    impl crate::command::ReplCommand for PingCommand {
+        fn clap_command() -> clap::Command {
+            clap::Command::new("ping")
+        }
+    }
+
+    fn on_ping(_trigger: On<PingCommand>, mut count: Local<u32>) {
+        *count += 1;
+        repl_println!("pong {}", *count);
+    }
+
+    fn setup(app: &mut App) {
+        app.add_message::<ReplBufferEvent>()
+            .add_repl_command::<PingCommand>()
+            .add_observer(on_ping);
+    }
+
+    fn pinned(app: &App) -> Vec<String> {
+        app.world().resource::<PinnedLines>().0.clone()
+    }
+
+    #[test]
+    fn test_watch_reruns_every_frame() {
+        let mut app = app((plugin, unwatch::plugin, setup));
+        assert!(run_ok(&mut app, "watch --frames 1 ping").is_empty());
+        assert_eq!(
+            pinned(&app),
+            vec!["every frame: ping  (Esc or 'unwatch' to stop)", "pong 1"]
+        );
+        app.update();
+        assert_eq!(pinned(&app)[1], "pong 2");
+
+        // Re-runs finish without reporting it
+        let watch = app.world().resource::<CommandWatch>();
+        let rerun = watch.0.as_ref().unwrap().running.unwrap();
+        let tracker = app.world().resource::<ReplCommandTracker>();
+        assert!(!tracker.is_running(rerun));
+        assert!(tracker.outcome(rerun).is_none());
+    }
+
+    #[test]
+    fn test_unwatch_and_clear_key_stop_watch() {
+        let mut app = app((plugin, unwatch::plugin, setup));
+        run_ok(&mut app, "watch ping");
+        assert_eq!(run_ok(&mut app, "unwatch ping"), vec!["stopped 1 watch"]);
+        assert!(app.world().resource::<CommandWatch>().0.is_none());
+        assert!(pinned(&app).is_empty());
+
+        run_ok(&mut app, "watch -n 1s ping");
+        app.world_mut().write_message(ReplBufferEvent::Clear);
+        app.update();
+        assert!(app.world().resource::<CommandWatch>().0.is_none());
+        assert!(pinned(&app).is_empty());
+    }
+
+    #[test]
+    fn test_watch_rejects_bad_commands() {
+        let mut app = app((plugin, unwatch::plugin, setup));
+        for line in ["watch watch ping", "watch nope", "watch -n 0s ping"] {
+            let (outcome, _) = run(&mut app, line);
+            assert!(matches!(outcome, CommandOutcome::Failed(_)), "{line}");
+        }
+        assert!(app.world().resource::<CommandWatch>().0.is_none());
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[1;31merror:\x1b[0m bad"), "error: bad");
+    }
+}
@@ -0,0 +1,278 @@
+use super::unwatch::{StoppedWatches, UnwatchCommand};
+use crate::command::ReplCommandTracker;
+use crate::prelude::*;
+use crate::reflection::{
+    find_registration, format_inline, parse_ron, MessageReadCursor, ReflectReplMessage,
+};
+use crate::repl_println;
+use bevy::prelude::*;
+use bevy::reflect::{GetPath, TypeRegistry};
+
+/// Widest a printed message may get before it is cut.
+const MAX_MESSAGE_WIDTH: usize = 200;
+
+pub fn plugin(app: &mut App) {
+    app.add_repl_command::<WatchMessagesCommand>();
+    app.add_observer(on_watch_messages);
+    app.add_observer(on_unwatch);
+    app.init_resource::<MessageWatches>();
+    app.add_systems(Last, print_watched_messages);
+}
+
+#[derive(Event, Clone, Default)]
+struct WatchMessagesCommand {
+    message: String,
+    limit: Option<usize>,
+    filters: Vec<(String, String)>,
+}
+
+fn parse_filter(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((path, value)) if !path.is_empty() => Ok((path.to_string(), value.to_string())),
+        _ => Err(format!("expected field=value, got '{s}'")),
+    }
+}
+
+impl crate::command::ReplCommand for WatchMessagesCommand {
+    fn clap_command() -> clap::Command {
+        use clap::{value_parser, Arg, ArgAction, Command};
+
+        Command::new("watch-messages")
+            .about("Print each message of a type as it is written")
+            .arg(
+                Arg::new("message")
+                    .required(true)
+                    .help("Message type, e.g. DamageEvent or my_game::DamageEvent"),
+            )
+            .arg(
+                Arg::new("limit")
+                    .short('n')
+                    .long("limit")
+                    .value_parser(value_parser!(usize))
+                    .help("Stop watching after this many messages"),
+            )
+            .arg(
+                Arg::new("filter")
+                    .short('f')
+                    .long("filter")
+                    .value_parser(parse_filter)
+                    .action(ArgAction::Append)
+                    .help("Only print messages whose field equals a value, e.g. target=12v0"),
+            )
+            .after_help("The message type needs #[reflect(ReplMessage)]. Stop with 'unwatch'.")
+    }
+
+    fn to_event(matches: &clap::ArgMatches) -> ReplResult<Self> {
+        Ok(Self {
+            message: matches
+                .get_one::<String>("message")
+                .cloned()
+                .unwrap_or_default(),
+            limit: matches.get_one::<usize>("limit").copied(),
+            filters: matches
+                .get_many::<(String, String)>("filter")
+                .map(|filters| filters.cloned().collect())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+struct MessageWatch {
+    /// Short type name, used for printing and `unwatch`.
+    name: String,
+    full_name: String,
+    reader: ReflectReplMessage,
+    cursor: MessageReadCursor,
+    filters: Vec<(String, String)>,
+    remaining: Option<usize>,
+}
+
+#[derive(Resource, Default)]
+struct MessageWatches(Vec<MessageWatch>);
+
+fn on_watch_messages(trigger: On<WatchMessagesCommand>, mut commands: Commands) {
+    let cmd = trigger.event().clone();
+    commands.queue(move |world: &mut World| {
+        if let Err(e) = add_watch(world, cmd) {
+            repl_println!("watch-messages: {e}");
+            world.resource_mut::<ReplCommandTracker>().fail(e);
+        }
+    });
+}
+
+fn add_watch(world: &mut World, cmd: WatchMessagesCommand) -> Result<(), String> {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let registration = find_registration(&registry, &cmd.message)?;
+    let paths = registration.type_info().type_path_table();
+    let (name, full_name) = (paths.short_path().to_string(), paths.path().to_string());
+    let reader = registration
+        .data::<ReflectReplMessage>()
+        .ok_or_else(|| format!("{name} is not a watchable message (add #[reflect(ReplMessage)])"))?
+        .clone();
+    let cursor = reader
+        .cursor(world)
+        .ok_or_else(|| format!("{name} has not been added with add_message"))?;
+
+    let watches = &mut world.resource_mut::<MessageWatches>().0;
+    watches.retain(|watch| watch.full_name != full_name);
+    watches.push(MessageWatch {
+        name: name.clone(),
+        full_name,
+        reader,
+        cursor,
+        filters: cmd.filters,
+        remaining: cmd.limit,
+    });
+    repl_println!("watching {name} (stop with 'unwatch {name}')");
+    Ok(())
+}
+
+fn on_unwatch(
+    trigger: On<UnwatchCommand>,
+    mut watches: ResMut<MessageWatches>,
+    mut stopped: ResMut<StoppedWatches>,
+) {
+    let before = watches.0.len();
+    match &trigger.event().target {
+        Some(name) => watches
+            .0
+            .retain(|watch| !(watch.name.eq_ignore_ascii_case(name) || watch.full_name == *name)),
+        None => watches.0.clear(),
+    }
+    stopped.0 += before - watches.0.len();
+}
+
+fn print_watched_messages(world: &mut World) {
+    if world.resource::<MessageWatches>().0.is_empty() {
+        return;
+    }
+    world.resource_scope(|world, mut watches: Mut<MessageWatches>| {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        watches.0.retain_mut(|watch| {
+            let MessageWatch {
+                name,
+                reader,
+                cursor,
+                filters,
+                remaining,
+                ..
+            } = watch;
+            reader.read(world, cursor, &mut |message| {
+                if *remaining == Some(0) {
+                    return;
+                }
+                let shown = filters
+                    .iter()
+                    .all(|(path, value)| field_equals(&registry, message, path, value));
+                if shown {
+                    repl_println!(
+                        "{}",
+                        format_inline(message.as_partial_reflect(), MAX_MESSAGE_WIDTH)
+                    );
+                    if let Some(remaining) = remaining {
+                        *remaining -= 1;
+                    }
+                }
+            });
+            if *remaining == Some(0) {
+                repl_println!("stopped watching {name} (limit reached)");
+                return false;
+            }
+            true
+        });
+    });
+}
+
+/// Compare a field to a value parsed as RON for the field's type, falling
+/// back to comparing the printed value, so `amount=3` matches `3.0`.
+fn field_equals(registry: &TypeRegistry, message: &dyn Reflect, path: &str, value: &str) -> bool {
+    let Ok(field) = message.reflect_path(path) else {
+        return false;
+    };
+    let parsed = field
+        .get_represented_type_info()
+        .and_then(|info| registry.get(info.type_id()))
+        .and_then(|registration| parse_ron(registry, registration, value).ok());
+    if parsed.is_some_and(|parsed| field.reflect_partial_eq(parsed.as_ref()) == Some(true)) {
+        return true;
+    }
+    let printed = format_inline(field, usize::MAX);
+    printed == value
+        || printed.trim_matches('"') == value
+        || matches!(
+            (printed.parse::<f64>(), value.parse::<f64>()),
+            (Ok(a), Ok(b)) if a == b
+        )
+}
+
+#[cfg(test)]
+mod watch_messages_tests {
+    use super::*;
+    use crate::built_ins::test_utils::{app, run, run_ok};
+    use crate::built_ins::unwatch;
+    use crate::command::CommandOutcome;
+    use crate::print::capture_output;
+
+    #[derive(Message, Reflect, Clone, Debug)]
+    #[reflect(ReplMessage)]
+    struct Damage {
+        target: u32,
+        amount: f32,
+    }
+
+    fn setup(app: &mut App) {
+        app.add_message::<Damage>().register_type::<Damage>();
+    }
+
+    fn write_damage(app: &mut App) {
+        app.world_mut().write_message(Damage {
+            target: 1,
+            amount: 3.0,
+        });
+        app.world_mut().write_message(Damage {
+            target: 2,
+            amount: 4.0,
+        });
+    }
+
+    #[test]
+    fn test_watch_messages_filter_and_limit() {
+        let mut app = app((plugin, unwatch::plugin, setup));
+        run_ok(&mut app, "watch-messages Damage -f target=2 -n 1");
+        write_damage(&mut app);
+        let ((), lines) = capture_output(|| app.update());
+        assert_eq!(
+            lines,
+            vec![
+                "Damage { target: 2, amount: 4.0 }",
+                "stopped watching Damage (limit reached)"
+            ]
+        );
+        write_damage(&mut app);
+        let ((), lines) = capture_output(|| app.update());
+        assert!(lines.is_empty());
+    }
+
+    #[test]
+    fn test_unwatch_messages() {
+        let mut app = app((plugin, unwatch::plugin, setup));
+        run_ok(&mut app, "watch-messages Damage");
+        assert_eq!(run_ok(&mut app, "unwatch damage"), vec!["stopped 1 watch"]);
+        write_damage(&mut app);
+        let ((), lines) = capture_output(|| app.update());
+        assert!(lines.is_empty());
+        let (outcome, _) = run(&mut app, "unwatch");
+        assert!(matches!(outcome, CommandOutcome::Failed(_)));
+    }
+
+    #[test]
+    fn test_watch_messages_needs_reflect_data() {
+        let mut app = app((plugin, unwatch::plugin, setup));
+        app.register_type::<Name>();
+        let (outcome, _) = run(&mut app, "watch-messages Name");
+        assert!(
+            matches!(outcome, CommandOutcome::Failed(e) if e.contains("not a watchable message"))
+        );
+    }
+}
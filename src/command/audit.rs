@@ -0,0 +1,273 @@
+//! Optional audit log of every dispatched command line.
+//!
+//! Each top-level command is appended to a file as one JSON object per line
+//! when it is submitted, and again when it finishes:
+//!
+//! ```text
+//! {"event":"submitted","handle":7,"timestamp":"2026-10-18T09:12:44.120Z","frame":5812,"session":"staging-1","user":"alice","source":"interactive","input":"despawn 12v1"}
+//! {"event":"finished","handle":7,"timestamp":"2026-10-18T09:12:44.120Z","frame":5812,"session":"staging-1","user":"alice","source":"interactive","input":"despawn 12v1","outcome":"ok","success":true,"elapsed_ms":0.412,"frames":0}
+//! ```
+//!
+//! Commands dispatched by other commands (e.g. the line run by `time`) are
+//! part of their parent's record, and `watch` re-runs are not logged.
+//!
+//! Enable it through the REPL plugin:
+//!
+//! ```ignore
+//! ReplPlugins.set(ReplPlugin::default().with_audit_log("logs/repl-audit.jsonl"))
+//! ```
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+
+use super::tracker::{ReplCommandFinished, ReplCommandSubmitted};
+
+/// Appends submitted and finished commands to a JSON-lines file. Inserted by
+/// [`ReplPlugin::with_audit_log`](crate::repl::ReplPlugin::with_audit_log).
+#[derive(Resource, Debug)]
+pub struct ReplAuditLog {
+    path: PathBuf,
+    session: String,
+    user: Option<String>,
+    file: Option<File>,
+    failed: bool,
+}
+
+impl ReplAuditLog {
+    /// Log to `path`, creating the file if needed. The session defaults to
+    /// `<pid>-<unix start time>` so lines from concurrent servers can be told
+    /// apart.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let started = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        Self {
+            path: path.into(),
+            session: format!("{}-{}", std::process::id(), started),
+            user: std::env::var("USER")
+                .or_else(|_| std::env::var("USERNAME"))
+                .ok(),
+            file: None,
+            failed: false,
+        }
+    }
+
+    /// Use a custom session name instead of the generated one.
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = session.into();
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn session(&self) -> &str {
+        &self.session
+    }
+
+    /// Append a record of a submitted command.
+    pub fn record_submitted(&mut self, submitted: &ReplCommandSubmitted) -> std::io::Result<()> {
+        let line = format_submitted(submitted, &self.session, self.user.as_deref());
+        self.append(line)
+    }
+
+    /// Append a record of a finished command.
+    pub fn record(&mut self, finished: &ReplCommandFinished) -> std::io::Result<()> {
+        let line = format_record(finished, &self.session, self.user.as_deref());
+        self.append(line)
+    }
+
+    /// Append one line. The file is opened on first use.
+    fn append(&mut self, mut line: String) -> std::io::Result<()> {
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => self.file.insert(
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&self.path)?,
+            ),
+        };
+        line.push('\n');
+        file.write_all(line.as_bytes())
+    }
+}
+
+/// Write every submitted and finished top-level command to the audit log.
+/// Submissions are written first, so a command submitted and finished in the
+/// same frame keeps its order. A failing log is reported once and then
+/// skipped.
+pub(crate) fn write_audit_log(
+    mut submitted: MessageReader<ReplCommandSubmitted>,
+    mut finished: MessageReader<ReplCommandFinished>,
+    mut audit: ResMut<ReplAuditLog>,
+) {
+    for started in submitted.read().filter(|started| started.parent.is_none()) {
+        if audit.failed {
+            continue;
+        }
+        if let Err(e) = audit.record_submitted(started) {
+            error!("Failed to write REPL audit log {:?}: {e}", audit.path);
+            audit.failed = true;
+        }
+    }
+    for done in finished.read().filter(|done| done.parent.is_none()) {
+        if audit.failed {
+            continue;
+        }
+        if let Err(e) = audit.record(done) {
+            error!("Failed to write REPL audit log {:?}: {e}", audit.path);
+            audit.failed = true;
+        }
+    }
+}
+
+fn format_submitted(submitted: &ReplCommandSubmitted, session: &str, user: Option<&str>) -> String {
+    let user = user.map_or("null".to_string(), json_string);
+    format!(
+        concat!(
+            "{{\"event\":\"submitted\",\"handle\":{},\"timestamp\":{},\"frame\":{},",
+            "\"session\":{},\"user\":{},\"source\":\"{}\",\"input\":{}}}"
+        ),
+        submitted.handle.0,
+        json_string(&rfc3339(submitted.submitted_at)),
+        submitted.frame,
+        json_string(session),
+        user,
+        submitted.source,
+        json_string(&submitted.input),
+    )
+}
+
+fn format_record(finished: &ReplCommandFinished, session: &str, user: Option<&str>) -> String {
+    let user = user.map_or("null".to_string(), json_string);
+    format!(
+        concat!(
+            "{{\"event\":\"finished\",\"handle\":{},\"timestamp\":{},\"frame\":{},",
+            "\"session\":{},\"user\":{},\"source\":\"{}\",\"input\":{},",
+            "\"outcome\":{},\"success\":{},\"elapsed_ms\":{:.3},\"frames\":{}}}"
+        ),
+        finished.handle.0,
+        json_string(&rfc3339(finished.submitted_at)),
+        finished.started_frame,
+        json_string(session),
+        user,
+        finished.source,
+        json_string(&finished.input),
+        json_string(&finished.outcome.to_string()),
+        finished.outcome.is_success(),
+        finished.elapsed.as_secs_f64() * 1000.0,
+        finished.frames,
+    )
+}
+
+/// Quote and escape a string as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Format a system time as an RFC 3339 UTC timestamp with milliseconds.
+fn rfc3339(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let secs = since_epoch.as_secs();
+    let (days, day_secs) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        day_secs / 3_600,
+        day_secs / 60 % 60,
+        day_secs % 60,
+        since_epoch.subsec_millis(),
+    )
+}
+
+#[cfg(test)]
+mod audit_tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::command::tracker::{CommandHandle, CommandOutcome, CommandSource};
+
+    #[test]
+    fn test_rfc3339() {
+        assert_eq!(rfc3339(UNIX_EPOCH), "1970-01-01T00:00:00.000Z");
+        let time = UNIX_EPOCH + Duration::from_millis(1_709_210_096_789);
+        assert_eq!(rfc3339(time), "2024-02-29T12:34:56.789Z");
+    }
+
+    #[test]
+    fn test_format_submitted() {
+        let submitted = ReplCommandSubmitted {
+            handle: CommandHandle(3),
+            input: "despawn 12v1".to_string(),
+            source: CommandSource::Interactive,
+            parent: None,
+            submitted_at: UNIX_EPOCH,
+            frame: 7,
+        };
+        assert_eq!(
+            format_submitted(&submitted, "s1", Some("alice")),
+            concat!(
+                r#"{"event":"submitted","handle":3,"timestamp":"1970-01-01T00:00:00.000Z","#,
+                r#""frame":7,"session":"s1","user":"alice","source":"interactive","#,
+                r#""input":"despawn 12v1"}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_record() {
+        let finished = ReplCommandFinished {
+            handle: CommandHandle(0),
+            input: "say \"hi\"\nthere".to_string(),
+            source: CommandSource::Script,
+            parent: None,
+            outcome: CommandOutcome::ParseError("unknown command 'sya'".to_string()),
+            submitted_at: UNIX_EPOCH,
+            elapsed: Duration::from_micros(1500),
+            frames: 0,
+            started_frame: 42,
+        };
+        assert_eq!(
+            format_record(&finished, "s1", None),
+            concat!(
+                r#"{"event":"finished","handle":0,"timestamp":"1970-01-01T00:00:00.000Z","#,
+                r#""frame":42,"session":"s1","user":null,"source":"script","#,
+                r#""input":"say \"hi\"\nthere","#,
+                r#""outcome":"parse error: unknown command 'sya'","success":false,"#,
+                r#""elapsed_ms":1.500,"frames":0}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,441 @@
+//! Parse error diagnostics for REPL input.
+//!
+//! Errors from tokenizing (`shell_words`) or parsing (`clap`) a submitted line
+//! are rendered the same way: the error message, the offending input echoed
+//! back with a caret under the failing column, and a usage/help hint. Output
+//! goes through [`repl_println!`](crate::repl_println) so it scrolls above the
+//! prompt like any other REPL output.
+//!
+//! ```text
+//! error: invalid value 'abc' for '--repeat <repeat>': invalid digit found in string
+//!   |
+//!   | say hello -r abc
+//!   |              ^^^
+//!   |
+//!   = usage: say [OPTIONS] <message>
+//!   = help: try 'say --help' for more information
+//! ```
+
+use std::ops::Range;
+
+use clap::builder::Styles;
+use clap::error::{ContextKind, ContextValue, ErrorKind};
+
+use crate::repl_println;
+
+/// A rendered-on-demand diagnostic for a single line of REPL input.
+#[derive(Debug, Clone)]
+pub struct ParseDiagnostic {
+    /// Error message, may already contain ANSI styling (e.g. from clap).
+    pub message: String,
+    /// The submitted input line.
+    pub input: String,
+    /// Byte range in `input` to underline. `None` skips the caret line.
+    pub span: Option<Range<usize>>,
+    /// Short note printed after the caret.
+    pub label: Option<String>,
+    /// Usage line for the command, if known.
+    pub usage: Option<String>,
+    /// Extra hints printed after the usage line.
+    pub help: Vec<String>,
+    summary: String,
+    styles: Styles,
+}
+
+impl ParseDiagnostic {
+    /// Create a diagnostic with an unstyled message.
+    pub fn new(input: &str, message: impl Into<String>) -> Self {
+        let styles = Styles::styled();
+        let error = styles.get_error();
+        let summary = message.into();
+        Self {
+            message: format!("{error}error:{error:#} {summary}"),
+            input: input.to_string(),
+            span: None,
+            label: None,
+            usage: None,
+            help: Vec::new(),
+            summary,
+            styles,
+        }
+    }
+
+    /// One-line, unstyled description of the error, e.g. for logs.
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    pub fn with_span(mut self, span: Range<usize>) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_usage(mut self, usage: impl Into<String>) -> Self {
+        self.usage = Some(usage.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help.push(help.into());
+        self
+    }
+
+    /// Diagnose input that `shell_words` failed to tokenize.
+    pub fn from_shell_error(input: &str, error: &shell_words::ParseError) -> Self {
+        let diagnostic = Self::new(input, error.to_string());
+        match unterminated_token(input) {
+            Some((pos, Unterminated::Quote(q))) => diagnostic
+                .with_span(pos..pos + 1)
+                .with_label(format!("this {q} quote is never closed"))
+                .with_help(format!("close the quote, or escape it with \\{q}")),
+            Some((pos, Unterminated::Escape)) => diagnostic
+                .with_span(pos..pos + 1)
+                .with_label("nothing to escape after this backslash")
+                .with_help("remove the trailing backslash, or quote it as '\\'"),
+            Some((_, Unterminated::Bracket(_))) | None => diagnostic,
+        }
+    }
+
+    /// Diagnose a clap error raised while parsing `input` with `cmd`.
+    pub fn from_clap_error(input: &str, error: &clap::Error, cmd: &clap::Command) -> Self {
+        let mut cmd = cmd.clone();
+        let styles = cmd.get_styles().clone();
+        let rendered = error.render();
+        let plain = rendered.to_string();
+        let ansi = rendered.ansi().to_string();
+
+        // Clap's rendering is the error message (possibly followed by indented
+        // details and `tip:` lines), then the usage and a "--help" trailer.
+        // Keep the message and tips; we print our own usage and help.
+        let mut message = Vec::new();
+        let mut tips = Vec::new();
+        for (plain, styled) in plain.lines().zip(ansi.lines()) {
+            if plain.starts_with("Usage:") || plain.starts_with("For more information") {
+                break;
+            }
+            if plain.trim().is_empty() {
+                continue;
+            }
+            match plain.trim_start().strip_prefix("tip:") {
+                Some(tip) => tips.push(tip.trim().to_string()),
+                None => message.push(styled),
+            }
+        }
+        let message = message.join("\n");
+
+        let usage = cmd.render_usage().ansi().to_string();
+        let usage = match usage.split_once(':') {
+            // Skip the "Usage:" header and the style reset that follows it.
+            Some((_, rest)) => rest
+                .trim_start()
+                .trim_start_matches("\x1b[0m")
+                .trim()
+                .to_string(),
+            None => usage,
+        };
+
+        let literal = *styles.get_literal();
+        let summary = plain
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches("error:")
+            .trim()
+            .to_string();
+        let mut diagnostic = Self {
+            message,
+            summary,
+            input: input.to_string(),
+            span: clap_error_span(input, error),
+            label: None,
+            usage: Some(usage),
+            help: tips,
+            styles,
+        };
+        diagnostic.help.push(format!(
+            "try '{literal}{} --help{literal:#}' for more information",
+            cmd.get_name()
+        ));
+        diagnostic
+    }
+
+    /// Diagnose input whose first token isn't a registered command.
+    pub fn unknown_command<'a>(
+        input: &str,
+        name: &str,
+        known: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let start = input.find(name).unwrap_or(0);
+        let mut diagnostic = Self::new(input, format!("unknown command '{name}'"))
+            .with_span(start..start + name.len());
+        if let Some(similar) = closest_match(name, known) {
+            let valid = *diagnostic.styles.get_valid();
+            diagnostic = diagnostic.with_help(format!(
+                "a similar command exists: '{valid}{similar}{valid:#}'"
+            ));
+        }
+        diagnostic.with_help("type 'help' to see available commands")
+    }
+
+    /// Print the diagnostic through the REPL printer.
+    pub fn emit(&self) {
+        for line in self.render_lines() {
+            repl_println!("{}", line);
+        }
+    }
+
+    /// Render the diagnostic into lines, without trailing newlines.
+    pub fn render_lines(&self) -> Vec<String> {
+        let gutter = self.styles.get_placeholder();
+        let error = self.styles.get_error();
+        let header = self.styles.get_usage();
+        let mut out: Vec<String> = self.message.lines().map(str::to_string).collect();
+
+        if let Some(span) = &self.span {
+            let input = self.input.as_str();
+            let start = span.start.min(input.len());
+            let end = span.end.clamp(start, input.len());
+            let label = self
+                .label
+                .as_deref()
+                .map(|l| format!(" {l}"))
+                .unwrap_or_default();
+            out.push(format!("{gutter}  |{gutter:#}"));
+            // Echo each input line (continuation lines included) and put the
+            // caret under the line that contains the start of the span.
+            let mut offset = 0;
+            for line in input.split('\n') {
+                out.push(format!("{gutter}  |{gutter:#} {line}"));
+                let line_end = offset + line.len();
+                if (offset..=line_end).contains(&start) {
+                    let col = line[..start - offset].chars().count();
+                    let width = input[start..end.min(line_end)].chars().count().max(1);
+                    out.push(format!(
+                        "{gutter}  |{gutter:#} {}{error}{}{label}{error:#}",
+                        " ".repeat(col),
+                        "^".repeat(width),
+                    ));
+                }
+                offset = line_end + 1;
+            }
+            out.push(format!("{gutter}  |{gutter:#}"));
+        }
+        if let Some(usage) = &self.usage {
+            out.push(format!(
+                "{gutter}  ={gutter:#} {header}usage:{header:#} {usage}"
+            ));
+        }
+        for help in &self.help {
+            out.push(format!(
+                "{gutter}  ={gutter:#} {header}help:{header:#} {help}"
+            ));
+        }
+        out
+    }
+}
+
+/// What was left open at the end of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Unterminated {
+    Quote(char),
+    Escape,
+    Bracket(char),
+}
+
+/// Find the byte position of an unclosed quote, a dangling escape, or an
+/// unclosed bracket, using the same quoting rules as `shell_words`.
+///
+/// Quotes and escapes take precedence since they are what `shell_words`
+/// rejects; brackets outside quotes only matter for line continuation.
+pub(crate) fn unterminated_token(input: &str) -> Option<(usize, Unterminated)> {
+    let mut quote: Option<(usize, char)> = None;
+    let mut escape: Option<usize> = None;
+    let mut brackets: Vec<(usize, char)> = Vec::new();
+    for (i, c) in input.char_indices() {
+        if escape.take().is_some() {
+            continue;
+        }
+        match (quote, c) {
+            (Some((_, '\'')), '\'') | (Some((_, '"')), '"') => quote = None,
+            (Some((_, '\'')), _) => {}
+            (_, '\\') => escape = Some(i),
+            (None, '\'' | '"') => quote = Some((i, c)),
+            (None, '(' | '[' | '{') => brackets.push((i, c)),
+            (None, ')' | ']' | '}') => {
+                let open = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if brackets.last().is_some_and(|(_, b)| *b == open) {
+                    brackets.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+    match (quote, escape, brackets.last()) {
+        (Some((pos, q)), _, _) => Some((pos, Unterminated::Quote(q))),
+        (None, Some(pos), _) => Some((pos, Unterminated::Escape)),
+        (None, None, Some((pos, b))) => Some((*pos, Unterminated::Bracket(*b))),
+        (None, None, None) => None,
+    }
+}
+
+/// Locate the part of `input` a clap error refers to.
+///
+/// Clap reports the offending value or argument as text rather than a
+/// position, so search for it after the command name. Errors about something
+/// missing point just past the end of the input.
+fn clap_error_span(input: &str, error: &clap::Error) -> Option<Range<usize>> {
+    let end = input.trim_end().len();
+    if matches!(
+        error.kind(),
+        ErrorKind::MissingRequiredArgument
+            | ErrorKind::MissingSubcommand
+            | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand
+            | ErrorKind::TooFewValues
+    ) {
+        return Some(end..end + 1);
+    }
+
+    let args_start = input
+        .trim_start()
+        .find(char::is_whitespace)
+        .map(|i| i + (input.len() - input.trim_start().len()))
+        .unwrap_or(end);
+    let find = |needle: &str| {
+        if needle.is_empty() {
+            return None;
+        }
+        input[args_start..]
+            .find(needle)
+            .map(|i| args_start + i..args_start + i + needle.len())
+    };
+    let context_str = |kind| match error.get(kind) {
+        Some(ContextValue::String(s)) => Some(s.as_str()),
+        _ => None,
+    };
+
+    if let Some(value) = context_str(ContextKind::InvalidValue) {
+        if value.is_empty() {
+            return Some(end..end + 1);
+        }
+        if let Some(span) = find(value) {
+            return Some(span);
+        }
+    }
+    for kind in [ContextKind::InvalidArg, ContextKind::InvalidSubcommand] {
+        // Argument names are rendered with their value placeholder, e.g.
+        // `--repeat <repeat>`; only the flag itself appears in the input.
+        if let Some(span) = context_str(kind)
+            .and_then(|a| a.split_whitespace().next())
+            .and_then(find)
+        {
+            return Some(span);
+        }
+    }
+    None
+}
+
+/// Pick the known name closest to `name` by edit distance, if any is close.
+pub(crate) fn closest_match<'a>(
+    name: &str,
+    known: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    known
+        .into_iter()
+        .map(|k| (edit_distance(name, k), k))
+        .filter(|(d, k)| *d <= k.len().max(name.len()).div_ceil(3))
+        .min()
+        .map(|(_, k)| k)
+}
+
+/// Levenshtein distance between two strings, by chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod diagnostic_tests {
+    use super::*;
+
+    fn say() -> clap::Command {
+        clap::Command::new("say")
+            .arg(clap::Arg::new("message").required(true))
+            .arg(
+                clap::Arg::new("repeat")
+                    .short('r')
+                    .long("repeat")
+                    .value_parser(clap::value_parser!(usize)),
+            )
+    }
+
+    #[test]
+    fn test_unterminated_token() {
+        assert_eq!(
+            unterminated_token("say \"hello"),
+            Some((4, Unterminated::Quote('"')))
+        );
+        assert_eq!(
+            unterminated_token("say 'it\"s"),
+            Some((4, Unterminated::Quote('\'')))
+        );
+        assert_eq!(
+            unterminated_token("say hello\\"),
+            Some((9, Unterminated::Escape))
+        );
+        assert_eq!(unterminated_token("say \"a\\\"b\""), None);
+        assert_eq!(
+            unterminated_token("spawn '{' (a, [b]"),
+            Some((10, Unterminated::Bracket('(')))
+        );
+        assert_eq!(unterminated_token("spawn (a, [b])"), None);
+    }
+
+    #[test]
+    fn test_clap_error_span_invalid_value() {
+        let input = "say hello -r abc";
+        let err = say().try_get_matches_from(shell_words::split(input).unwrap());
+        let span = clap_error_span(input, &err.unwrap_err()).unwrap();
+        assert_eq!(&input[span], "abc");
+    }
+
+    #[test]
+    fn test_clap_error_span_unknown_argument() {
+        let input = "say hello --loud";
+        let err = say().try_get_matches_from(shell_words::split(input).unwrap());
+        let span = clap_error_span(input, &err.unwrap_err()).unwrap();
+        assert_eq!(&input[span], "--loud");
+    }
+
+    #[test]
+    fn test_clap_error_span_missing_argument() {
+        let input = "say";
+        let err = say().try_get_matches_from(shell_words::split(input).unwrap());
+        assert_eq!(clap_error_span(input, &err.unwrap_err()), Some(3..4));
+    }
+
+    #[test]
+    fn test_closest_match() {
+        let known = ["quit", "help", "clear"];
+        assert_eq!(closest_match("qiut", known), Some("quit"));
+        assert_eq!(closest_match("hlep", known), Some("help"));
+        assert_eq!(closest_match("spawn", known), None);
+    }
+}
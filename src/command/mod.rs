@@ -1,13 +1,32 @@
 use anyhow::Result;
 use bevy::prelude::*;
 
+pub mod audit;
+pub mod diagnostic;
 pub mod parser;
 pub mod register;
+pub mod tracker;
+pub mod value_parser;
 
+pub use audit::ReplAuditLog;
+pub use diagnostic::ParseDiagnostic;
 pub use parser::{
-    parse_input_buffer_for_commands, CommandParser, ParserPlugin, TypedCommandParser,
+    dispatch_command, dispatch_command_from, parse_input_buffer_for_commands, CommandParser,
+    ParserPlugin, TypedCommandParser,
+};
+pub use register::{register_command_in_repl, ReplAppExt, ReplOneshots};
+pub use tracker::{
+    CommandHandle, CommandOutcome, CommandSource, ReplCommandFinished, ReplCommandSubmitted,
+    ReplCommandTracker,
+};
+#[cfg(feature = "color")]
+pub use value_parser::parse_color;
+#[cfg(feature = "regex")]
+pub use value_parser::parse_name_regex;
+pub use value_parser::{
+    parse_duration, parse_entity, parse_entity_selector, parse_quat, parse_vec2, parse_vec3,
+    parse_vec4, EntitySelector,
 };
-pub use register::{register_command_in_repl, ReplAppExt};
 
 pub type ReplResult<T> = Result<T, clap::error::Error>;
 
@@ -184,6 +203,23 @@ mod repl_buffer_tests {
         assert!(repl.buffer.is_empty());
     }
 
+    #[test]
+    fn test_repl_buffer_multiline() {
+        let mut repl = Repl::default();
+        assert_eq!(repl.line_count(), 1);
+        repl.insert('a');
+        repl.insert('\n');
+        repl.insert('b');
+        assert_eq!(repl.buffer, "a\nb");
+        assert_eq!(repl.line_count(), 2);
+        assert!(crate::prompt::input::needs_continuation("say \"a"));
+        assert!(crate::prompt::input::needs_continuation("spawn {"));
+        assert!(crate::prompt::input::needs_continuation("say a \\"));
+        assert!(!crate::prompt::input::needs_continuation("say \"a\nb\""));
+        assert!(crate::prompt::input::needs_continuation("say :("));
+        assert!(!crate::prompt::input::needs_continuation("say :(\n"));
+    }
+
     #[test]
     fn test_repl_buffer_bounds() {
         let mut repl = Repl::default();
@@ -1,3 +1,9 @@
+use super::audit::{write_audit_log, ReplAuditLog};
+use super::diagnostic::ParseDiagnostic;
+use super::tracker::{
+    emit_finished_commands, emit_submitted_commands, sync_tracker_frame, CommandHandle,
+    CommandOutcome, CommandSource, ReplCommandFinished, ReplCommandSubmitted, ReplCommandTracker,
+};
 use super::ReplCommand;
 use crate::repl::{Repl, ReplSubmitEvent};
 use crate::repl_println;
@@ -7,15 +13,49 @@ pub struct ParserPlugin;
 
 impl Plugin for ParserPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<ReplCommandTracker>();
+        app.add_message::<ReplCommandSubmitted>();
+        app.add_message::<ReplCommandFinished>();
+        app.add_systems(First, sync_tracker_frame);
         app.add_systems(
             Update,
             parse_input_buffer_for_commands.in_set(InputSet::EmitBevy),
         );
+        app.add_systems(
+            PostUpdate,
+            (
+                emit_submitted_commands,
+                emit_finished_commands,
+                write_audit_log.run_if(resource_exists::<ReplAuditLog>),
+            )
+                .chain(),
+        );
     }
 }
 
 pub trait CommandParser: Send + Sync {
+    /// Parse `input` and trigger the command event if it is valid. Parse
+    /// errors and help text are printed to the REPL. Returns whether the
+    /// input was handled, which includes printing an error for it; `false`
+    /// leaves input this parser doesn't recognize, such as an unknown
+    /// argument or subcommand, to other parsers without printing anything.
     fn parse_and_trigger(&self, input: &str, commands: &mut Commands) -> bool;
+
+    /// Like [`parse_and_trigger`](Self::parse_and_trigger), but reports how
+    /// parsing went so the dispatcher can record it with the
+    /// [`ReplCommandTracker`]. Defaults to a generic parse error when the
+    /// input was not handled.
+    fn parse_and_trigger_with_outcome(
+        &self,
+        input: &str,
+        commands: &mut Commands,
+    ) -> CommandOutcome {
+        if self.parse_and_trigger(input, commands) {
+            CommandOutcome::Success
+        } else {
+            CommandOutcome::ParseError(format!("could not parse '{input}'"))
+        }
+    }
 }
 
 pub struct TypedCommandParser<C: ReplCommand> {
@@ -32,90 +72,187 @@ impl<C: ReplCommand> TypedCommandParser<C> {
 
 impl<C: ReplCommand> CommandParser for TypedCommandParser<C> {
     fn parse_and_trigger(&self, input: &str, bevy_commands: &mut Commands) -> bool {
-        // Tokenize the input like a shell (handles quotes/escapes)
-        let argv = match shell_words::split(input) {
-            Ok(v) => v,
-            Err(_) => {
-                // Not a valid shell-like input; let other parsers try
-                return false;
-            }
+        // Not a valid shell-like input; let other parsers try
+        let Ok(argv) = shell_words::split(input) else {
+            return false;
         };
-
         // Empty input shouldn't be handled here
         if argv.is_empty() {
             return false;
         }
+        // If this looks like an unrelated command token, let others try
+        if let Err(clap_error) = C::clap_command().try_get_matches_from(&argv) {
+            use clap::error::ErrorKind;
+            if matches!(
+                clap_error.kind(),
+                ErrorKind::UnknownArgument | ErrorKind::InvalidSubcommand
+            ) {
+                return false;
+            }
+        }
+        self.parse_and_trigger_with_outcome(input, bevy_commands);
+        true
+    }
+
+    fn parse_and_trigger_with_outcome(
+        &self,
+        input: &str,
+        bevy_commands: &mut Commands,
+    ) -> CommandOutcome {
+        // Tokenize the input like a shell (handles quotes/escapes)
+        let argv = match shell_words::split(input) {
+            Ok(v) => v,
+            Err(e) => {
+                let diagnostic = ParseDiagnostic::from_shell_error(input, &e);
+                diagnostic.emit();
+                return CommandOutcome::ParseError(diagnostic.summary().to_string());
+            }
+        };
 
         // Try parsing with clap; argv already includes the command/alias at [0]
         let cmd = C::clap_command();
-        match cmd.try_get_matches_from(&argv) {
-            Ok(matches) => {
-                match C::to_event(&matches) {
-                    Ok(event) => bevy_commands.trigger(event),
-                    Err(clap_error) => {
-                        for line in format!("{}", clap_error).lines() {
-                            repl_println!("{}", line);
-                        }
-                    }
+        let clap_error = match cmd.clone().try_get_matches_from(&argv) {
+            Ok(matches) => match C::to_event(&matches) {
+                Ok(event) => {
+                    bevy_commands.trigger(event);
+                    return CommandOutcome::Success;
                 }
-                true
-            }
-            Err(clap_error) => {
-                // If this looks like an unrelated command token, let others try
-                use clap::error::ErrorKind;
-                match clap_error.kind() {
-                    ErrorKind::DisplayHelp | ErrorKind::DisplayVersion => {
-                        // Print help/version text via REPL so it appears above the prompt
-                        for line in format!("{}", clap_error).lines() {
-                            repl_println!("{}", line);
-                        }
-                        true
-                    }
-                    ErrorKind::UnknownArgument | ErrorKind::InvalidSubcommand => false,
-                    _ => {
-                        // Print the Clap error message with preserved formatting
-                        for line in format!("{}", clap_error).lines() {
-                            repl_println!("{}", line);
-                        }
-                        true
-                    }
+                Err(clap_error) => clap_error,
+            },
+            Err(clap_error) => clap_error,
+        };
+
+        use clap::error::ErrorKind;
+        match clap_error.kind() {
+            ErrorKind::DisplayHelp | ErrorKind::DisplayVersion => {
+                // Print help/version text via REPL so it appears above the prompt
+                for line in clap_error.render().ansi().to_string().lines() {
+                    repl_println!("{}", line);
                 }
+                CommandOutcome::Displayed
+            }
+            _ => {
+                let diagnostic = ParseDiagnostic::from_clap_error(input, &clap_error, &cmd);
+                diagnostic.emit();
+                CommandOutcome::ParseError(diagnostic.summary().to_string())
             }
         }
     }
 }
 
-/// System that parses terminal input and triggers command observers
+/// System that forwards submitted input to [`dispatch_command`].
 pub fn parse_input_buffer_for_commands(
     mut submitted_text: MessageReader<ReplSubmitEvent>,
     mut bevy_commands: Commands,
-    repl: Res<Repl>,
 ) {
     for event in submitted_text.read() {
         let input = event.0.clone();
-        // Skip empty input
-        if input.is_empty() {
-            continue;
-        }
-        // Tokenize input and dispatch by the first token (command name or alias)
-        let argv = match shell_words::split(&input) {
+        bevy_commands.queue(move |world: &mut World| {
+            dispatch_command(world, &input);
+        });
+    }
+}
+
+/// Parse a line of input and run the matching command's observers.
+///
+/// The command is dispatched by its first token (command name or alias) and
+/// tracked by the [`ReplCommandTracker`] until it finishes. Observers run
+/// before this function returns. Returns `None` for blank input.
+///
+/// Commands dispatched from inside another command's observer inherit its
+/// [`CommandSource`]; anything else is [`CommandSource::Interactive`].
+pub fn dispatch_command(world: &mut World, input: &str) -> Option<CommandHandle> {
+    let source = world
+        .resource::<ReplCommandTracker>()
+        .current_source()
+        .unwrap_or_default();
+    dispatch_command_from(world, input, source)
+}
+
+/// Like [`dispatch_command`], but records where the line came from, e.g.
+/// [`CommandSource::Script`] for lines read from a file.
+pub fn dispatch_command_from(
+    world: &mut World,
+    input: &str,
+    source: CommandSource,
+) -> Option<CommandHandle> {
+    // Skip empty input
+    if input.trim().is_empty() {
+        return None;
+    }
+    let handle = world
+        .resource_mut::<ReplCommandTracker>()
+        .begin(input, source);
+    let outcome = world.resource_scope(|world, repl: Mut<Repl>| {
+        // Tokenize input to find the command name or alias
+        let argv = match shell_words::split(input) {
             Ok(v) => v,
-            Err(_) => {
-                error!("Invalid input: {}", input);
-                continue;
+            Err(e) => {
+                let diagnostic = ParseDiagnostic::from_shell_error(input, &e);
+                diagnostic.emit();
+                return CommandOutcome::ParseError(diagnostic.summary().to_string());
             }
         };
-        if argv.is_empty() {
-            continue;
+        let Some(key) = argv.first() else {
+            return CommandOutcome::Success;
+        };
+        match repl.commands.get(key) {
+            Some(parser) => parser.parse_and_trigger_with_outcome(input, &mut world.commands()),
+            None => {
+                let diagnostic = ParseDiagnostic::unknown_command(
+                    input,
+                    key,
+                    repl.commands.keys().map(String::as_str),
+                );
+                diagnostic.emit();
+                CommandOutcome::ParseError(diagnostic.summary().to_string())
+            }
         }
-        let key = &argv[0];
-        if let Some(parser) = repl.commands.get(key) {
-            let _ = parser.parse_and_trigger(&input, &mut bevy_commands);
-        } else {
-            error!(
-                "Unknown command '{}'. Type 'help' to see available commands.",
-                key
-            );
+    });
+    // Run the observers (and whatever they queue) while this command is current
+    world.flush();
+    world
+        .resource_mut::<ReplCommandTracker>()
+        .end(handle, outcome);
+    Some(handle)
+}
+
+#[cfg(test)]
+mod parser_tests {
+    use super::*;
+    use crate::print::capture_output;
+    use bevy::ecs::world::CommandQueue;
+    use clap::{value_parser, Arg, Command};
+
+    #[derive(Event, Clone, Default)]
+    struct PingCommand;
+
+    impl ReplCommand for PingCommand {
+        fn clap_command() -> Command {
+            Command::new("ping").arg(
+                Arg::new("count")
+                    .required(true)
+                    .value_parser(value_parser!(u32)),
+            )
         }
     }
+
+    #[test]
+    fn test_parse_and_trigger_leaves_unrecognized_input() {
+        let world = World::new();
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let parser = TypedCommandParser::<PingCommand>::new();
+        let mut parse = |input| capture_output(|| parser.parse_and_trigger(input, &mut commands));
+
+        assert_eq!(parse("ping 3"), (true, vec![]));
+        // Errors printed for the command count as handled
+        let (handled, lines) = parse("ping abc");
+        assert!(handled);
+        assert!(!lines.is_empty());
+        // Unknown arguments and invalid shell input are left to other parsers
+        assert_eq!(parse("ping 3 --loud"), (false, vec![]));
+        assert_eq!(parse("ping \"3"), (false, vec![]));
+        assert_eq!(parse(""), (false, vec![]));
+    }
 }
@@ -1,11 +1,21 @@
+use std::collections::BTreeMap;
+
 use super::{CommandParser, ReplCommand, TypedCommandParser};
 use crate::repl::Repl;
+use bevy::ecs::system::SystemId;
 use bevy::prelude::*;
 
 /// Extension trait for App to add REPL commands
 pub trait ReplAppExt {
     /// Add a REPL command with its observer function
     fn add_repl_command<C: ReplCommand>(&mut self) -> &mut Self;
+
+    /// Register a one-shot system under `name`, for the `run` command
+    fn add_repl_oneshot<M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<(), (), M> + 'static,
+    ) -> &mut Self;
 }
 
 impl ReplAppExt for App {
@@ -13,8 +23,28 @@ impl ReplAppExt for App {
         self.add_systems(Startup, register_command_in_repl::<C>);
         self
     }
+
+    fn add_repl_oneshot<M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<(), (), M> + 'static,
+    ) -> &mut Self {
+        let id = self.world_mut().register_system(system);
+        self.world_mut()
+            .get_resource_or_init::<ReplOneshots>()
+            .0
+            .insert(name.into(), id);
+        self
+    }
 }
 
+/// One-shot systems the `run` command can run, by name.
+///
+/// Filled by [`ReplAppExt::add_repl_oneshot`]. A [`SystemId`] registered
+/// elsewhere can be inserted directly.
+#[derive(Resource, Default, Debug)]
+pub struct ReplOneshots(pub BTreeMap<String, SystemId>);
+
 // System to register commands in the REPL
 pub fn register_command_in_repl<C: ReplCommand>(mut repl: ResMut<Repl>) {
     let cmd = C::clap_command();
@@ -0,0 +1,400 @@
+//! Tracking of dispatched command lines from submission to completion.
+//!
+//! Every line the dispatcher runs gets a [`CommandHandle`]. A command is
+//! considered finished once its observers (and any commands they queue) have
+//! run, which happens in the same frame it was submitted. Commands whose work
+//! spans several frames can [`defer`](ReplCommandTracker::defer) completion
+//! from their observer and [`complete`](ReplCommandTracker::complete) it later.
+//!
+//! Every dispatched command emits a [`ReplCommandSubmitted`] message when it
+//! starts and a [`ReplCommandFinished`] message with its outcome, wall-clock
+//! duration, and the number of frames it took when it finishes. Commands
+//! dispatched while another command runs (e.g. by `time`) record that command
+//! as their `parent`.
+//!
+//! ```ignore
+//! fn on_reload(_: On<ReloadCommand>, mut tracker: ResMut<ReplCommandTracker>, mut commands: Commands) {
+//!     if let Some(handle) = tracker.defer() {
+//!         commands.insert_resource(PendingReload(handle));
+//!     }
+//! }
+//!
+//! fn finish_reload(pending: Res<PendingReload>, mut tracker: ResMut<ReplCommandTracker>) {
+//!     tracker.complete(pending.0, CommandOutcome::Success);
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant, SystemTime};
+
+use bevy::diagnostic::FrameCount;
+use bevy::prelude::*;
+
+/// Identifies one dispatched command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CommandHandle(pub u64);
+
+/// Where a dispatched command line came from.
+///
+/// The REPL itself only produces [`Interactive`](Self::Interactive) and
+/// [`Watch`](Self::Watch). [`Script`](Self::Script) and
+/// [`Remote`](Self::Remote) are for apps that feed lines to
+/// [`dispatch_command_from`](super::dispatch_command_from) themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CommandSource {
+    /// Typed at the prompt.
+    #[default]
+    Interactive,
+    /// Read from a script or startup file.
+    Script,
+    /// Received from another process, e.g. over a socket.
+    Remote,
+    /// Re-run by the `watch` command. Re-runs are tracked, but don't emit
+    /// [`ReplCommandSubmitted`] or [`ReplCommandFinished`] themselves.
+    Watch,
+}
+
+impl CommandSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Interactive => "interactive",
+            Self::Script => "script",
+            Self::Remote => "remote",
+            Self::Watch => "watch",
+        }
+    }
+}
+
+impl std::fmt::Display for CommandSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How a dispatched command line ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandOutcome {
+    /// The command's handlers ran, or the command reported success.
+    Success,
+    /// Help or version text was displayed instead of running the command.
+    Displayed,
+    /// The input could not be parsed or names an unknown command.
+    ParseError(String),
+    /// The command reported a failure.
+    Failed(String),
+}
+
+impl CommandOutcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success | Self::Displayed)
+    }
+}
+
+impl std::fmt::Display for CommandOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Success => write!(f, "ok"),
+            Self::Displayed => write!(f, "displayed"),
+            Self::ParseError(e) => write!(f, "parse error: {e}"),
+            Self::Failed(e) => write!(f, "failed: {e}"),
+        }
+    }
+}
+
+/// Emitted once for every dispatched command line when it starts.
+#[derive(Message, Debug, Clone)]
+pub struct ReplCommandSubmitted {
+    pub handle: CommandHandle,
+    /// The submitted line.
+    pub input: String,
+    pub source: CommandSource,
+    /// The command that dispatched this one, if any.
+    pub parent: Option<CommandHandle>,
+    /// System time the command was dispatched at.
+    pub submitted_at: SystemTime,
+    /// The frame the command was dispatched on.
+    pub frame: u32,
+}
+
+/// Emitted once for every dispatched command line when it finishes.
+#[derive(Message, Debug, Clone)]
+pub struct ReplCommandFinished {
+    pub handle: CommandHandle,
+    /// The submitted line.
+    pub input: String,
+    pub source: CommandSource,
+    /// The command that dispatched this one, if any.
+    pub parent: Option<CommandHandle>,
+    pub outcome: CommandOutcome,
+    /// System time the command was dispatched at.
+    pub submitted_at: SystemTime,
+    /// Wall-clock time from dispatch to completion.
+    pub elapsed: Duration,
+    /// Frames from dispatch to completion (0 if it finished the same frame).
+    pub frames: u32,
+    /// The frame the command was dispatched on.
+    pub started_frame: u32,
+}
+
+/// A command that has been dispatched but not finished yet.
+#[derive(Debug, Clone)]
+struct RunningCommand {
+    input: String,
+    source: CommandSource,
+    parent: Option<CommandHandle>,
+    submitted_at: SystemTime,
+    started: Instant,
+    started_frame: u32,
+    deferred: bool,
+    failure: Option<String>,
+}
+
+/// Keeps track of running commands. See the [module docs](self).
+#[derive(Resource, Debug, Default)]
+pub struct ReplCommandTracker {
+    next_id: u64,
+    frame: u32,
+    running: HashMap<CommandHandle, RunningCommand>,
+    /// Commands whose observers are running right now, innermost last.
+    current: Vec<CommandHandle>,
+    submitted: Vec<ReplCommandSubmitted>,
+    finished: Vec<ReplCommandFinished>,
+}
+
+/// Whether a command's messages are left out, so periodic `watch` re-runs
+/// don't flood listeners like the audit log. Commands they dispatch are not.
+fn is_quiet(source: CommandSource, parent: Option<CommandHandle>) -> bool {
+    source == CommandSource::Watch && parent.is_none()
+}
+
+impl ReplCommandTracker {
+    /// Start tracking a command line and mark it as the current command.
+    pub fn begin(&mut self, input: &str, source: CommandSource) -> CommandHandle {
+        let handle = CommandHandle(self.next_id);
+        self.next_id += 1;
+        let parent = self.current();
+        let submitted_at = SystemTime::now();
+        if !is_quiet(source, parent) {
+            self.submitted.push(ReplCommandSubmitted {
+                handle,
+                input: input.to_string(),
+                source,
+                parent,
+                submitted_at,
+                frame: self.frame,
+            });
+        }
+        self.running.insert(
+            handle,
+            RunningCommand {
+                input: input.to_string(),
+                source,
+                parent,
+                submitted_at,
+                started: Instant::now(),
+                started_frame: self.frame,
+                deferred: false,
+                failure: None,
+            },
+        );
+        self.current.push(handle);
+        handle
+    }
+
+    /// Stop treating `handle` as the current command. Completes it with
+    /// `outcome` unless an observer deferred completion. A failure reported
+    /// with [`fail`](Self::fail) takes precedence over a successful outcome.
+    pub fn end(&mut self, handle: CommandHandle, outcome: CommandOutcome) {
+        self.current.retain(|h| *h != handle);
+        let Some(running) = self.running.get_mut(&handle) else {
+            return;
+        };
+        let outcome = match (outcome, running.failure.take()) {
+            (outcome, Some(error)) if outcome.is_success() => CommandOutcome::Failed(error),
+            (outcome, _) => outcome,
+        };
+        if !running.deferred || !outcome.is_success() {
+            self.complete(handle, outcome);
+        }
+    }
+
+    /// The command whose observers are currently running, if any.
+    pub fn current(&self) -> Option<CommandHandle> {
+        self.current.last().copied()
+    }
+
+    /// Where the current command came from, if a command is running.
+    pub fn current_source(&self) -> Option<CommandSource> {
+        self.current()
+            .and_then(|handle| self.running.get(&handle))
+            .map(|running| running.source)
+    }
+
+    /// Keep the current command running after its observers return. Call
+    /// [`complete`](Self::complete) with the returned handle once the work is
+    /// done. Returns `None` outside of a command observer.
+    pub fn defer(&mut self) -> Option<CommandHandle> {
+        let handle = self.current()?;
+        if let Some(running) = self.running.get_mut(&handle) {
+            running.deferred = true;
+        }
+        Some(handle)
+    }
+
+    /// Mark the current command as failed, e.g. when an observer couldn't do
+    /// what it was asked. The command still finishes as usual.
+    pub fn fail(&mut self, error: impl Into<String>) {
+        if let Some(running) = self
+            .current()
+            .and_then(|handle| self.running.get_mut(&handle))
+        {
+            running.failure = Some(error.into());
+        }
+    }
+
+    /// Report that a command finished. Does nothing if it already finished.
+    pub fn complete(&mut self, handle: CommandHandle, outcome: CommandOutcome) {
+        let Some(running) = self.running.remove(&handle) else {
+            return;
+        };
+        if is_quiet(running.source, running.parent) {
+            return;
+        }
+        self.finished.push(ReplCommandFinished {
+            handle,
+            input: running.input,
+            source: running.source,
+            parent: running.parent,
+            outcome,
+            submitted_at: running.submitted_at,
+            elapsed: running.started.elapsed(),
+            frames: self.frame.wrapping_sub(running.started_frame),
+            started_frame: running.started_frame,
+        });
+    }
+
+    /// Whether the command is still running.
+    pub fn is_running(&self, handle: CommandHandle) -> bool {
+        self.running.contains_key(&handle)
+    }
+
+    /// The frame number the tracker uses for new and finished commands.
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// How a finished command ended, until its message is emitted.
+    #[cfg(test)]
+    pub(crate) fn outcome(&self, handle: CommandHandle) -> Option<&CommandOutcome> {
+        self.finished
+            .iter()
+            .find(|finished| finished.handle == handle)
+            .map(|finished| &finished.outcome)
+    }
+}
+
+/// Keep the tracker's frame number in sync with Bevy's [`FrameCount`].
+pub(crate) fn sync_tracker_frame(
+    frame: Option<Res<FrameCount>>,
+    mut tracker: ResMut<ReplCommandTracker>,
+) {
+    if let Some(frame) = frame {
+        tracker.frame = frame.0;
+    }
+}
+
+/// Emit a [`ReplCommandSubmitted`] message for every command dispatched since
+/// the last run.
+pub(crate) fn emit_submitted_commands(
+    mut tracker: ResMut<ReplCommandTracker>,
+    mut writer: MessageWriter<ReplCommandSubmitted>,
+) {
+    if !tracker.submitted.is_empty() {
+        writer.write_batch(std::mem::take(&mut tracker.submitted));
+    }
+}
+
+/// Emit a [`ReplCommandFinished`] message for every command completed since
+/// the last run.
+pub(crate) fn emit_finished_commands(
+    mut tracker: ResMut<ReplCommandTracker>,
+    mut writer: MessageWriter<ReplCommandFinished>,
+) {
+    if !tracker.finished.is_empty() {
+        writer.write_batch(std::mem::take(&mut tracker.finished));
+    }
+}
+
+#[cfg(test)]
+mod tracker_tests {
+    use super::*;
+
+    #[test]
+    fn test_command_completes_when_ended() {
+        let mut tracker = ReplCommandTracker::default();
+        let handle = tracker.begin("ping", CommandSource::Interactive);
+        assert_eq!(tracker.current(), Some(handle));
+        tracker.end(handle, CommandOutcome::Success);
+        assert_eq!(tracker.current(), None);
+        assert!(!tracker.is_running(handle));
+        assert_eq!(tracker.finished.len(), 1);
+        assert_eq!(tracker.finished[0].input, "ping");
+        assert_eq!(tracker.submitted.len(), 1);
+        assert_eq!(tracker.submitted[0].handle, handle);
+    }
+
+    #[test]
+    fn test_nested_command_records_parent() {
+        let mut tracker = ReplCommandTracker::default();
+        let outer = tracker.begin("time ping", CommandSource::Interactive);
+        let inner = tracker.begin("ping", CommandSource::Interactive);
+        tracker.end(inner, CommandOutcome::Success);
+        tracker.end(outer, CommandOutcome::Success);
+        assert_eq!(tracker.submitted[0].parent, None);
+        assert_eq!(tracker.submitted[1].parent, Some(outer));
+        assert_eq!(tracker.finished[0].parent, Some(outer));
+        assert_eq!(tracker.finished[1].parent, None);
+    }
+
+    #[test]
+    fn test_watch_reruns_are_quiet() {
+        let mut tracker = ReplCommandTracker::default();
+        let rerun = tracker.begin("time ping", CommandSource::Watch);
+        let inner = tracker.begin("ping", CommandSource::Watch);
+        tracker.end(inner, CommandOutcome::Success);
+        tracker.end(rerun, CommandOutcome::Success);
+        assert!(!tracker.is_running(rerun));
+        // Only the nested command is reported, e.g. for `time` to see it
+        assert_eq!(tracker.submitted.len(), 1);
+        assert_eq!(tracker.finished.len(), 1);
+        assert_eq!(tracker.finished[0].handle, inner);
+    }
+
+    #[test]
+    fn test_deferred_command_completes_later() {
+        let mut tracker = ReplCommandTracker::default();
+        let handle = tracker.begin("reload", CommandSource::Script);
+        assert_eq!(tracker.current_source(), Some(CommandSource::Script));
+        assert_eq!(tracker.defer(), Some(handle));
+        tracker.end(handle, CommandOutcome::Success);
+        assert!(tracker.is_running(handle));
+
+        tracker.frame += 3;
+        tracker.complete(handle, CommandOutcome::Success);
+        assert!(!tracker.is_running(handle));
+        assert_eq!(tracker.finished[0].frames, 3);
+        assert_eq!(tracker.defer(), None);
+    }
+
+    #[test]
+    fn test_failed_command() {
+        let mut tracker = ReplCommandTracker::default();
+        let handle = tracker.begin("resource get Nope", CommandSource::Interactive);
+        tracker.fail("no resource named 'Nope'");
+        tracker.end(handle, CommandOutcome::Success);
+        assert_eq!(
+            tracker.finished[0].outcome,
+            CommandOutcome::Failed("no resource named 'Nope'".to_string())
+        );
+    }
+}
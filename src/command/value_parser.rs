@@ -0,0 +1,428 @@
+//! Bevy-aware `clap` value parsers for REPL command arguments.
+//!
+//! Each parser is a plain `fn(&str) -> Result<T, String>`, which clap accepts
+//! anywhere a value parser is expected. That works for both command styles:
+//!
+//! ```ignore
+//! // Builder pattern
+//! clap::Arg::new("position").value_parser(parse_vec3);
+//!
+//! // Derive pattern
+//! #[arg(value_parser = parse_vec3)]
+//! position: Vec3,
+//! ```
+//!
+//! Vectors accept comma and/or whitespace separated components, optionally
+//! wrapped in parentheses or brackets: `1,2,3`, `1 2 3`, `(1.0, 2.0, 3.0)`.
+
+use std::time::Duration;
+
+use bevy::ecs::entity::{EntityGeneration, EntityIndex};
+use bevy::prelude::*;
+
+/// Parse an [`Entity`] from its display form (`12v1`) or a bare index (`12`).
+///
+/// A bare index resolves to the first generation of that index, which matches
+/// entities that have never been despawned and respawned.
+pub fn parse_entity(s: &str) -> Result<Entity, String> {
+    let s = s.trim();
+    let (index, generation) = match s.split_once('v') {
+        Some((index, generation)) => (index, Some(generation)),
+        None => (s, None),
+    };
+    let index = index
+        .parse::<u32>()
+        .ok()
+        .and_then(EntityIndex::from_raw_u32)
+        .ok_or_else(|| format!("invalid entity '{s}', expected <index> or <index>v<generation>"))?;
+    let generation = match generation {
+        Some(g) => g
+            .parse::<u32>()
+            .map(EntityGeneration::from_bits)
+            .map_err(|_| format!("invalid entity generation in '{s}'"))?,
+        None => EntityGeneration::FIRST,
+    };
+    Ok(Entity::from_index_and_generation(index, generation))
+}
+
+/// An entity argument given either as an ID or as a [`Name`].
+///
+/// Use [`parse_entity_selector`] as the value parser and resolve the selector
+/// against the world once the command runs. Which variants exist depends on
+/// crate features (`NameRegex` needs `regex`), so matches need a wildcard arm.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum EntitySelector {
+    /// An explicit entity ID, e.g. `12v1`.
+    Id(Entity),
+    /// The exact value of a [`Name`] component.
+    Name(String),
+    /// A glob over [`Name`] values, where `*` matches any run of characters
+    /// and `?` matches one character, e.g. `Enemy*`.
+    NameGlob(String),
+    /// A regular expression searched for in [`Name`] values, written as
+    /// `/pattern/`, e.g. `/^Enemy \d+$/`.
+    #[cfg(feature = "regex")]
+    NameRegex(regex::Regex),
+}
+
+impl PartialEq for EntitySelector {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Id(a), Self::Id(b)) => a == b,
+            (Self::Name(a), Self::Name(b)) | (Self::NameGlob(a), Self::NameGlob(b)) => a == b,
+            #[cfg(feature = "regex")]
+            (Self::NameRegex(a), Self::NameRegex(b)) => a.as_str() == b.as_str(),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for EntitySelector {}
+
+impl Default for EntitySelector {
+    fn default() -> Self {
+        Self::Id(Entity::PLACEHOLDER)
+    }
+}
+
+impl std::fmt::Display for EntitySelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Id(entity) => write!(f, "{entity}"),
+            Self::Name(name) | Self::NameGlob(name) => write!(f, "\"{name}\""),
+            #[cfg(feature = "regex")]
+            Self::NameRegex(regex) => write!(f, "/{regex}/"),
+        }
+    }
+}
+
+impl EntitySelector {
+    /// Whether an entity with the given (optional) name is selected.
+    pub fn matches(&self, entity: Entity, name: Option<&Name>) -> bool {
+        match self {
+            Self::Id(id) => *id == entity,
+            Self::Name(wanted) => name.is_some_and(|n| n.as_str() == wanted),
+            Self::NameGlob(pattern) => name.is_some_and(|n| glob_match(pattern, n.as_str())),
+            #[cfg(feature = "regex")]
+            Self::NameRegex(regex) => name.is_some_and(|n| regex.is_match(n.as_str())),
+        }
+    }
+
+    /// Find every live entity in the world selected by this selector.
+    pub fn resolve(&self, world: &mut World) -> Vec<Entity> {
+        match self {
+            Self::Id(entity) => world
+                .get_entity(*entity)
+                .map(|e| vec![e.id()])
+                .unwrap_or_default(),
+            _ => {
+                let mut query = world.query::<(Entity, &Name)>();
+                query
+                    .iter(world)
+                    .filter(|(entity, name)| self.matches(*entity, Some(name)))
+                    .map(|(entity, _)| entity)
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Parse an [`EntitySelector`]: anything that looks like an entity ID is an ID,
+/// names containing `*` or `?` are globs, `/pattern/` is a regular expression
+/// (with the `regex` feature), and everything else is treated as an exact
+/// [`Name`].
+pub fn parse_entity_selector(s: &str) -> Result<EntitySelector, String> {
+    if s.trim().is_empty() {
+        return Err("expected an entity ID or name".to_string());
+    }
+    #[cfg(feature = "regex")]
+    if let Some(pattern) = s.strip_prefix('/').and_then(|rest| rest.strip_suffix('/')) {
+        return parse_name_regex(pattern);
+    }
+    Ok(match parse_entity(s) {
+        Ok(entity) => EntitySelector::Id(entity),
+        Err(_) if s.contains(['*', '?']) => EntitySelector::NameGlob(s.to_string()),
+        Err(_) => EntitySelector::Name(s.to_string()),
+    })
+}
+
+/// Parse a regular expression over [`Name`] values, given without the
+/// surrounding slashes.
+#[cfg(feature = "regex")]
+pub fn parse_name_regex(pattern: &str) -> Result<EntitySelector, String> {
+    regex::Regex::new(pattern)
+        .map(EntitySelector::NameRegex)
+        .map_err(|e| format!("invalid regex: {e}"))
+}
+
+/// Match `text` against a glob where `*` matches any run of characters
+/// (including none) and `?` matches exactly one.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text position it was tried at
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` swallow one more character
+                Some((star, start)) => {
+                    p = star + 1;
+                    t = start + 1;
+                    backtrack = Some((star, start + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Split a vector-like string into exactly `N` floats.
+fn parse_floats<const N: usize>(s: &str) -> Result<[f32; N], String> {
+    let inner = s
+        .trim()
+        .trim_start_matches(['(', '['])
+        .trim_end_matches([')', ']']);
+    let parts: Vec<&str> = inner
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if parts.len() != N {
+        return Err(format!(
+            "expected {N} components, found {} in '{s}'",
+            parts.len()
+        ));
+    }
+    let mut out = [0.0; N];
+    for (slot, part) in out.iter_mut().zip(parts) {
+        *slot = part
+            .parse::<f32>()
+            .map_err(|_| format!("'{part}' is not a number"))?;
+    }
+    Ok(out)
+}
+
+/// Parse a [`Vec2`] such as `1.0,2.0`.
+pub fn parse_vec2(s: &str) -> Result<Vec2, String> {
+    parse_floats::<2>(s).map(Vec2::from_array)
+}
+
+/// Parse a [`Vec3`] such as `1.0,2.0,3.0`.
+pub fn parse_vec3(s: &str) -> Result<Vec3, String> {
+    parse_floats::<3>(s).map(Vec3::from_array)
+}
+
+/// Parse a [`Vec4`] such as `1.0,2.0,3.0,4.0`.
+pub fn parse_vec4(s: &str) -> Result<Vec4, String> {
+    parse_floats::<4>(s).map(Vec4::from_array)
+}
+
+/// Parse a [`Quat`] from XYZ euler angles in degrees, e.g. `0,90,0`.
+pub fn parse_quat(s: &str) -> Result<Quat, String> {
+    let [x, y, z] = parse_floats::<3>(s)?;
+    Ok(Quat::from_euler(
+        EulerRot::XYZ,
+        x.to_radians(),
+        y.to_radians(),
+        z.to_radians(),
+    ))
+}
+
+/// Parse a [`Duration`] with a unit suffix: `250ms`, `2s`, `1.5m`, `1h`,
+/// `100us` or `10ns`. A bare number is read as seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value = value
+        .parse::<f64>()
+        .map_err(|_| format!("invalid duration '{s}', expected e.g. 250ms or 2s"))?;
+    let secs = match unit.trim() {
+        "" | "s" | "sec" | "secs" => value,
+        "ms" => value / 1_000.0,
+        "us" | "µs" => value / 1_000_000.0,
+        "ns" => value / 1_000_000_000.0,
+        "m" | "min" | "mins" => value * 60.0,
+        "h" => value * 3_600.0,
+        other => return Err(format!("unknown duration unit '{other}' in '{s}'")),
+    };
+    Duration::try_from_secs_f64(secs).map_err(|e| format!("invalid duration '{s}': {e}"))
+}
+
+/// Parse a [`Color`] from a hex code (`#ff8800`, `ff8800cc`), a basic named
+/// color (`red`, `teal`, ...) or sRGB float components (`1,0.5,0` or
+/// `rgba(1, 0.5, 0, 0.8)`).
+#[cfg(feature = "color")]
+pub fn parse_color(s: &str) -> Result<Color, String> {
+    use bevy::color::palettes::basic;
+
+    let s = s.trim();
+    let lower = s.to_ascii_lowercase();
+    let named = match lower.as_str() {
+        "aqua" | "cyan" => Some(basic::AQUA),
+        "black" => Some(basic::BLACK),
+        "blue" => Some(basic::BLUE),
+        "fuchsia" | "magenta" => Some(basic::FUCHSIA),
+        "gray" | "grey" => Some(basic::GRAY),
+        "green" => Some(basic::GREEN),
+        "lime" => Some(basic::LIME),
+        "maroon" => Some(basic::MAROON),
+        "navy" => Some(basic::NAVY),
+        "olive" => Some(basic::OLIVE),
+        "purple" => Some(basic::PURPLE),
+        "red" => Some(basic::RED),
+        "silver" => Some(basic::SILVER),
+        "teal" => Some(basic::TEAL),
+        "white" => Some(basic::WHITE),
+        "yellow" => Some(basic::YELLOW),
+        "transparent" | "none" => Some(Srgba::NONE),
+        _ => None,
+    };
+    if let Some(color) = named {
+        return Ok(color.into());
+    }
+    if let Some(inner) = lower
+        .strip_prefix("rgba")
+        .or_else(|| lower.strip_prefix("rgb"))
+    {
+        return parse_color_components(inner);
+    }
+    if s.contains([',', ' ']) {
+        return parse_color_components(s);
+    }
+    Srgba::hex(s)
+        .map(Color::from)
+        .map_err(|e| format!("invalid color '{s}': {e}"))
+}
+
+#[cfg(feature = "color")]
+fn parse_color_components(s: &str) -> Result<Color, String> {
+    match parse_floats::<4>(s) {
+        Ok([r, g, b, a]) => Ok(Color::srgba(r, g, b, a)),
+        Err(_) => parse_floats::<3>(s).map(|[r, g, b]| Color::srgb(r, g, b)),
+    }
+}
+
+#[cfg(test)]
+mod value_parser_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entity() {
+        let entity = parse_entity("12v1").unwrap();
+        assert_eq!(entity.index_u32(), 12);
+        assert_eq!(entity.to_string(), "12v1");
+        assert_eq!(parse_entity("7").unwrap().index_u32(), 7);
+        assert!(parse_entity("Bob").is_err());
+        assert!(parse_entity("12vx").is_err());
+    }
+
+    #[test]
+    fn test_parse_entity_selector() {
+        assert!(matches!(
+            parse_entity_selector("3v1"),
+            Ok(EntitySelector::Id(_))
+        ));
+        assert_eq!(
+            parse_entity_selector("Bob"),
+            Ok(EntitySelector::Name("Bob".to_string()))
+        );
+        assert_eq!(
+            parse_entity_selector("Orc*"),
+            Ok(EntitySelector::NameGlob("Orc*".to_string()))
+        );
+        assert!(parse_entity_selector("").is_err());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_parse_name_regex() {
+        let selector = parse_entity_selector("/^Orc \\d+$/").unwrap();
+        assert_eq!(selector, parse_name_regex("^Orc \\d+$").unwrap());
+        assert!(selector.matches(Entity::PLACEHOLDER, Some(&Name::new("Orc 12"))));
+        assert!(!selector.matches(Entity::PLACEHOLDER, Some(&Name::new("Orc chief"))));
+        assert_eq!(selector.to_string(), "/^Orc \\d+$/");
+        assert!(parse_entity_selector("/(/").is_err());
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("Orc*", "Orc 1"));
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*chief", "Orc chief"));
+        assert!(glob_match("O?c*f", "Orc chief"));
+        assert!(!glob_match("Orc?", "Orc 12"));
+        assert!(!glob_match("Orc*", "Goblin"));
+    }
+
+    #[test]
+    fn test_entity_selector_resolve() {
+        let mut world = World::new();
+        let bob = world.spawn(Name::new("Bob")).id();
+        world.spawn(Name::new("Alice"));
+        let selector = EntitySelector::Name("Bob".to_string());
+        assert_eq!(selector.resolve(&mut world), vec![bob]);
+        assert_eq!(EntitySelector::Id(bob).resolve(&mut world), vec![bob]);
+        let glob = EntitySelector::NameGlob("B*".to_string());
+        assert_eq!(glob.resolve(&mut world), vec![bob]);
+    }
+
+    #[test]
+    fn test_parse_vectors() {
+        assert_eq!(parse_vec2("1,2").unwrap(), Vec2::new(1.0, 2.0));
+        assert_eq!(
+            parse_vec3("(1.0, 2.0, 3.0)").unwrap(),
+            Vec3::new(1.0, 2.0, 3.0)
+        );
+        assert_eq!(parse_vec3("1 2 3").unwrap(), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            parse_vec4("[1,2,3,4]").unwrap(),
+            Vec4::new(1.0, 2.0, 3.0, 4.0)
+        );
+        assert!(parse_vec3("1,2").is_err());
+        assert!(parse_vec2("1,a").is_err());
+    }
+
+    #[test]
+    fn test_parse_quat() {
+        let q = parse_quat("0,90,0").unwrap();
+        let expected = Quat::from_rotation_y(90f32.to_radians());
+        assert!(q.abs_diff_eq(expected, 1e-5));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert_eq!(parse_duration("2s").unwrap(), Duration::from_secs(2));
+        assert_eq!(parse_duration("1.5").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_duration("2m").unwrap(), Duration::from_secs(120));
+        assert!(parse_duration("2 fortnights").is_err());
+        assert!(parse_duration("ms").is_err());
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("red").unwrap(), Color::srgb(1.0, 0.0, 0.0));
+        assert_eq!(parse_color("#ff0000").unwrap(), Color::srgb(1.0, 0.0, 0.0));
+        assert_eq!(
+            parse_color("rgba(1, 0, 0, 0.5)").unwrap(),
+            Color::srgba(1.0, 0.0, 0.0, 0.5)
+        );
+        assert_eq!(parse_color("0,0,1").unwrap(), Color::srgb(0.0, 0.0, 1.0));
+        assert!(parse_color("notacolor").is_err());
+    }
+}
@@ -22,6 +22,8 @@ pub mod log_ecs;
 pub mod plugin;
 pub mod print;
 pub mod prompt;
+#[cfg(feature = "reflect")]
+pub mod reflection;
 pub mod repl;
 
 pub mod prelude {
@@ -30,8 +32,10 @@ pub mod prelude {
     pub use crate::command::ReplCommand;
     pub use crate::command::{ReplAppExt, ReplResult};
     pub use crate::prompt::{
+        completion::ReplCompletions,
         keymap::{Binding as ReplKeybind, PromptKeymap},
         renderer::{simple::SimpleRenderer, ActiveRenderer, PromptRenderPlugin, PromptRenderer},
+        scroll::PinnedLines,
         PromptPlugin, ReplPrompt, ReplPromptConfig,
     };
     pub use crate::repl::{
@@ -41,14 +45,16 @@ pub mod prelude {
     // This allows: `use bevy_repl::prelude::*;` then `repl_println!(...)`.
     pub use crate::repl_println;
     // Low-level printer if callers prefer a function over the macro.
-    pub use crate::print::repl_print;
+    pub use crate::print::{capture_output, repl_print};
 
     pub use crate::context::ReplContextPlugin;
     pub use crate::log_ecs::{
         custom_layer as repl_log_custom_layer, print_log_events_system, tracing_to_repl_fmt,
-        tracing_to_repl_fmt_with_level, LogEvent,
+        tracing_to_repl_fmt_with_level, LogEvent, ReplLogFilter,
     };
     pub use crate::plugin::ReplPlugins;
+    #[cfg(feature = "reflect")]
+    pub use crate::reflection::ReflectReplMessage;
 
     #[cfg(feature = "derive")]
     pub use bevy_repl_derive::ReplCommand;
@@ -1,11 +1,11 @@
 //! ECS log capture from tracing layer to Bevy `Event<LogEvent>`.
 //! Based on bevy's `log_layers_ecs.rs` example, adapted to print via the REPL.
 
-use std::sync::mpsc;
+use std::sync::{mpsc, OnceLock};
 
 use bevy::log::{
     tracing::{self, Subscriber},
-    tracing_subscriber::{self as ts, Layer},
+    tracing_subscriber::{self as ts, filter::LevelFilter, reload, EnvFilter, Layer, Registry},
     BoxedLayer,
 };
 use bevy::prelude::*;
@@ -25,6 +25,13 @@ impl Plugin for ReplLogPrintPlugin {
             // screen.
             tracing_to_repl_fmt();
         }
+        // Hand the global fmt layer's filter, if there is one, to the app
+        let fmt_filter = FMT_FILTER
+            .get()
+            .filter(|_| !app.world().contains_resource::<ReplLogFilter>());
+        if let Some(filter) = fmt_filter {
+            app.insert_resource(filter.clone());
+        }
         app.add_message::<LogEvent>();
         app.add_systems(
             Update,
@@ -87,6 +94,15 @@ impl tracing::field::Visit for CaptureLayerVisitor<'_> {
 
 /// Create a CaptureLayer and register the plumbing in the provided `App`.
 /// Returns the boxed Layer to be attached to Bevy's `LogPlugin` via its `custom_layer`.
+///
+/// The layer has its own reloadable filter, inserted as the [`ReplLogFilter`]
+/// resource. It lets everything through until the `log` command's plugin
+/// starts it from `LogPlugin`'s settings (see
+/// [`ReplLogFilter::reset_to`]). `LogPlugin`'s own `level` and `filter` still
+/// apply to every layer first, so the REPL filter can only narrow them. To let
+/// the `log` command raise levels too, set
+/// `LogPlugin { level: Level::TRACE, filter: String::new(), .. }`; the REPL
+/// filter then owns verbosity for the captured logs.
 pub fn custom_layer(app: &mut App) -> Option<BoxedLayer> {
     let (sender, receiver) = mpsc::channel();
     let layer = CaptureLayer { sender };
@@ -95,7 +111,9 @@ pub fn custom_layer(app: &mut App) -> Option<BoxedLayer> {
     app.add_message::<LogEvent>();
     app.add_systems(Update, transfer_log_events.in_set(ReplSet::Pre));
 
-    Some(layer.boxed())
+    let (filter, reloadable) = ReplLogFilter::new(LevelFilter::TRACE.to_string());
+    app.insert_resource(reloadable);
+    Some(layer.with_filter(filter).boxed())
 }
 
 /// Convenience system that prints captured `LogEvent`s via the REPL printer so they appear
@@ -157,22 +175,75 @@ pub fn tracing_to_repl_fmt() {
 
 /// Same as `install_tracing_to_repl_fmt`, but lets you choose the max log level
 /// (to mirror the `level` used by Bevy's `LogPlugin`).
+///
+/// The level can be changed at runtime through the [`ReplLogFilter`]
+/// resource, which [`ReplPlugins`](crate::ReplPlugins) inserts.
 pub fn tracing_to_repl_fmt_with_level(level: bevy::log::Level) {
-    use ts::filter::LevelFilter;
-    use ts::{fmt, prelude::*, registry::Registry};
-
-    let lf = match level {
-        bevy::log::Level::ERROR => LevelFilter::ERROR,
-        bevy::log::Level::WARN => LevelFilter::WARN,
-        bevy::log::Level::INFO => LevelFilter::INFO,
-        bevy::log::Level::DEBUG => LevelFilter::DEBUG,
-        bevy::log::Level::TRACE => LevelFilter::TRACE,
-    };
+    use ts::{fmt, prelude::*};
 
+    let (filter, reloadable) = ReplLogFilter::new(LevelFilter::from_level(level).to_string());
     let layer = fmt::layer()
         .with_ansi(true)
         .with_writer(ReplMakeWriter)
-        .with_filter(lf);
+        .with_filter(filter);
+
+    // Another subscriber (e.g. LogPlugin's) may already be global, in which
+    // case this filter would never see an event
+    if Registry::default().with(layer).try_init().is_ok() {
+        let _ = FMT_FILTER.set(reloadable);
+    }
+}
 
-    let _ = Registry::default().with(layer).try_init();
+/// The filter of the global fmt layer. The subscriber is process-wide, so
+/// there is at most one.
+static FMT_FILTER: OnceLock<ReplLogFilter> = OnceLock::new();
+
+// --- Runtime filter control ---
+
+/// The reloadable filter of the REPL's log layer, installed by
+/// [`tracing_to_repl_fmt_with_level`] or [`custom_layer`], and the directives
+/// it started with. The `log` command changes it through this resource.
+#[derive(Resource, Clone)]
+pub struct ReplLogFilter {
+    handle: reload::Handle<EnvFilter, Registry>,
+    defaults: String,
+}
+
+impl ReplLogFilter {
+    /// A filter starting at `defaults`, in [`EnvFilter`] syntax, and the layer
+    /// it controls. The filter can only be changed while the layer is alive.
+    pub fn new(defaults: impl Into<String>) -> (reload::Layer<EnvFilter, Registry>, Self) {
+        let defaults = defaults.into();
+        let (layer, handle) = reload::Layer::new(EnvFilter::new(&defaults));
+        (layer, Self { handle, defaults })
+    }
+
+    /// The active directives, e.g. `info,my_game::ai=trace`.
+    pub fn directives(&self) -> Result<String, String> {
+        self.handle
+            .with_current(|filter| filter.to_string())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Replace the filter with `directives` in [`EnvFilter`] syntax.
+    pub fn set(&self, directives: &str) -> Result<(), String> {
+        let filter = EnvFilter::builder()
+            .parse(directives)
+            .map_err(|e| format!("invalid filter '{directives}': {e}"))?;
+        self.handle.reload(filter).map_err(|e| e.to_string())
+    }
+
+    /// Restore the directives the filter started with.
+    pub fn reset(&self) -> Result<(), String> {
+        self.set(&self.defaults)
+    }
+
+    /// Switch to `defaults`, and restore them on [`reset`](Self::reset) from
+    /// now on.
+    pub fn reset_to(&mut self, defaults: impl Into<String>) -> Result<(), String> {
+        let defaults = defaults.into();
+        self.set(&defaults)?;
+        self.defaults = defaults;
+        Ok(())
+    }
 }
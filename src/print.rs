@@ -8,6 +8,7 @@
 //!
 //! This avoids newline/cursor issues that can happen in raw or alternate screen modes.
 
+use std::cell::RefCell;
 use std::io::{stdout, Write};
 use std::sync::atomic::{AtomicU16, AtomicU64, Ordering};
 
@@ -45,6 +46,25 @@ pub fn printed_lines() -> usize {
     PRINT_COUNT.load(Ordering::Relaxed).try_into().unwrap()
 }
 
+thread_local! {
+    // Lines printed while `capture_output` runs on this thread
+    static CAPTURE: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Run `f`, collecting the lines it prints with [`repl_println!`] on this
+/// thread instead of writing them to the terminal.
+///
+/// Output printed from other threads, or after `f` returns (e.g. by a command
+/// that finishes on a later frame), is not captured.
+pub fn capture_output<R>(f: impl FnOnce() -> R) -> (R, Vec<String>) {
+    let previous = CAPTURE.with(|capture| capture.replace(Some(Vec::new())));
+    let result = f();
+    let lines = CAPTURE
+        .with(|capture| capture.replace(previous))
+        .unwrap_or_default();
+    (result, lines)
+}
+
 /// Low-level function used by [`repl_println!`] to print a formatted line.
 ///
 /// # Scroll Region Behavior
@@ -66,6 +86,15 @@ pub fn printed_lines() -> usize {
 ///
 /// This function is typically not called directly; prefer using [`repl_println!`] for convenience.
 pub fn repl_print(args: std::fmt::Arguments) -> std::io::Result<()> {
+    if CAPTURE.with(|capture| capture.borrow().is_some()) {
+        let line = args.to_string();
+        CAPTURE.with(|capture| {
+            if let Some(lines) = capture.borrow_mut().as_mut() {
+                lines.push(line);
+            }
+        });
+        return Ok(());
+    }
     let mut out = stdout();
     // If a scroll region is active (pretty mode), move to the last scrollable line
     // so output scrolls ABOVE the prompt area. When we position the cursor explicitly,
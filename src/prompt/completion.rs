@@ -0,0 +1,140 @@
+//! Tab completion of command names and arguments.
+//!
+//! The first word completes to a registered command name or alias. The word
+//! after it completes from the candidates a command registered in
+//! [`ReplCompletions`], e.g. the one-shot system names of `run`.
+
+use std::collections::{BTreeSet, HashMap};
+
+use bevy::prelude::*;
+
+/// Candidates for the first argument of a command, by command name.
+///
+/// ```ignore
+/// fn sync_level_names(levels: Res<Levels>, mut completions: ResMut<ReplCompletions>) {
+///     completions.set_arguments("load", levels.names().cloned());
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct ReplCompletions(pub HashMap<String, Vec<String>>);
+
+impl ReplCompletions {
+    /// Replace the argument candidates of `command`.
+    pub fn set_arguments(
+        &mut self,
+        command: impl Into<String>,
+        candidates: impl IntoIterator<Item = String>,
+    ) {
+        self.0
+            .insert(command.into(), candidates.into_iter().collect());
+    }
+}
+
+/// What pressing Tab does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Completion {
+    /// Nothing matches.
+    None,
+    /// Insert this text at the cursor.
+    Insert(String),
+    /// Several candidates match and share no longer prefix.
+    Candidates(Vec<String>),
+}
+
+/// Complete the word in front of the cursor. `before_cursor` is the input up
+/// to the cursor, and `commands` the registered command names and aliases.
+pub fn complete<'a>(
+    before_cursor: &str,
+    commands: impl IntoIterator<Item = &'a str>,
+    completions: &ReplCompletions,
+) -> Completion {
+    let words: Vec<&str> = before_cursor.split_whitespace().collect();
+    let at_word_start = before_cursor.is_empty() || before_cursor.ends_with(char::is_whitespace);
+    let (position, partial) = match words.split_last() {
+        Some((last, _)) if !at_word_start => (words.len() - 1, *last),
+        _ => (words.len(), ""),
+    };
+    let candidates: BTreeSet<&str> = match position {
+        0 => commands.into_iter().collect(),
+        1 => completions
+            .0
+            .get(words[0])
+            .map(|args| args.iter().map(String::as_str).collect())
+            .unwrap_or_default(),
+        _ => return Completion::None,
+    };
+    let matches: Vec<&str> = candidates
+        .into_iter()
+        .filter(|candidate| candidate.starts_with(partial))
+        .collect();
+    match matches.as_slice() {
+        [] => Completion::None,
+        [only] => Completion::Insert(format!("{} ", &only[partial.len()..])),
+        [first, rest @ ..] => {
+            let common = rest.iter().fold(*first, |common, candidate| {
+                let len = common
+                    .char_indices()
+                    .zip(candidate.chars())
+                    .take_while(|((_, a), b)| a == b)
+                    .last()
+                    .map_or(0, |((i, a), _)| i + a.len_utf8());
+                &common[..len]
+            });
+            if common.len() > partial.len() {
+                Completion::Insert(common[partial.len()..].to_string())
+            } else {
+                Completion::Candidates(matches.iter().map(|m| m.to_string()).collect())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod completion_tests {
+    use super::*;
+
+    const COMMANDS: [&str; 4] = ["help", "quit", "run", "resource"];
+
+    fn completions() -> ReplCompletions {
+        let mut completions = ReplCompletions::default();
+        completions.set_arguments("run", ["reset_ai".to_string(), "reset_map".to_string()]);
+        completions
+    }
+
+    #[test]
+    fn test_complete_command_name() {
+        assert_eq!(
+            complete("q", COMMANDS, &completions()),
+            Completion::Insert("uit ".to_string())
+        );
+        assert_eq!(
+            complete("r", COMMANDS, &completions()),
+            Completion::Candidates(vec!["resource".to_string(), "run".to_string()])
+        );
+        assert_eq!(complete("x", COMMANDS, &completions()), Completion::None);
+    }
+
+    #[test]
+    fn test_complete_argument() {
+        assert_eq!(
+            complete("run reset_", COMMANDS, &completions()),
+            Completion::Candidates(vec!["reset_ai".to_string(), "reset_map".to_string()])
+        );
+        assert_eq!(
+            complete("run r", COMMANDS, &completions()),
+            Completion::Insert("eset_".to_string())
+        );
+        assert_eq!(
+            complete("run reset_m", COMMANDS, &completions()),
+            Completion::Insert("ap ".to_string())
+        );
+        assert_eq!(
+            complete("run reset_ai ", COMMANDS, &completions()),
+            Completion::None
+        );
+        assert_eq!(
+            complete("help q", COMMANDS, &completions()),
+            Completion::None
+        );
+    }
+}
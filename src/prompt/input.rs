@@ -4,13 +4,17 @@ use bevy_ratatui::crossterm::event::KeyEventKind as CrosstermKeyEventKind;
 use bevy_ratatui::event::KeyMessage;
 use std::io::{stdout, Write};
 
+use crate::command::diagnostic::unterminated_token;
+use crate::prompt::completion::{complete, Completion, ReplCompletions};
 use crate::prompt::keymap::PromptKeymap;
 use crate::repl::{Repl, ReplBufferEvent, ReplSet, ReplSubmitEvent};
+use crate::repl_println;
 
 pub struct PromptInputPlugin;
 
 impl Plugin for PromptInputPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<ReplCompletions>();
         app.add_systems(
             Update,
             (
@@ -37,6 +41,7 @@ impl Plugin for PromptInputPlugin {
 /// custom keybinds.
 fn update_repl_buffer(
     mut repl: ResMut<Repl>,
+    completions: Res<ReplCompletions>,
     mut buffer_events: MessageReader<ReplBufferEvent>,
     mut parse_events: MessageWriter<ReplSubmitEvent>,
 ) {
@@ -66,8 +71,29 @@ fn update_repl_buffer(
             ReplBufferEvent::Clear => {
                 repl.clear_buffer();
             }
+            ReplBufferEvent::Complete => {
+                let before_cursor = &repl.buffer[..repl.cursor_pos];
+                let commands = repl.commands.keys().map(String::as_str);
+                match complete(before_cursor, commands, &completions) {
+                    Completion::Insert(text) => text.chars().for_each(|c| repl.insert(c)),
+                    Completion::Candidates(candidates) => {
+                        repl_println!("{}", candidates.join("  "))
+                    }
+                    Completion::None => {}
+                }
+            }
             ReplBufferEvent::Submit => {
-                let input = repl.drain_buffer();
+                if needs_continuation(&repl.buffer) {
+                    // Keep the prompt open and continue on a new line
+                    repl.end();
+                    repl.insert('\n');
+                    continue;
+                }
+                let mut input = repl.drain_buffer();
+                // Drop the empty line that forced an incomplete input through
+                if input.ends_with('\n') {
+                    input.pop();
+                }
                 // Print a newline to move terminal to next line
                 let _ = stdout().write_all(b"\r");
                 parse_events.write(ReplSubmitEvent(input));
@@ -76,6 +102,15 @@ fn update_repl_buffer(
     }
 }
 
+/// Whether submitted input is incomplete and should continue on another line:
+/// it ends with a backslash, or has an unclosed quote or bracket.
+///
+/// Input whose last line is empty is never continued, so pressing Enter on an
+/// empty continuation line submits it as it is (e.g. `say :(`).
+pub fn needs_continuation(input: &str) -> bool {
+    !input.ends_with('\n') && unterminated_token(input).is_some()
+}
+
 /// System that blocks keyboard input from being forwarded to Bevy when REPL is enabled to
 /// prevent key events from reaching game systems while typing into the prompt.
 pub(super) fn block_keyboard_input_forwarding(
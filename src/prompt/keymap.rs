@@ -71,9 +71,15 @@ fn normalize_nonchar_mods(mods: KeyModifiers) -> KeyModifiers {
 ///     }
 /// }
 /// ```
+///
+/// New actions get new fields, so build a keymap from [`PromptKeymap::default`]
+/// or [`PromptKeymap::none`] and assign the bindings you want to change.
 #[derive(Resource, Debug, Clone)]
+#[non_exhaustive]
 pub struct PromptKeymap {
     pub submit: Option<Binding>,
+    /// Insert a literal newline to continue the input on another line.
+    pub newline: Option<Binding>,
     pub backspace: Option<Binding>,
     pub left: Option<Binding>,
     pub right: Option<Binding>,
@@ -81,6 +87,8 @@ pub struct PromptKeymap {
     pub end: Option<Binding>,
     pub delete: Option<Binding>,
     pub clear: Option<Binding>,
+    /// Complete the command name or argument in front of the cursor.
+    pub complete: Option<Binding>,
     // whether to insert plain chars (no modifiers) into buffer
     pub allow_plain_char_insert: bool,
 }
@@ -94,6 +102,10 @@ impl Default for PromptKeymap {
                 code: K::Enter,
                 mods: M::NONE,
             }),
+            newline: Some(Binding {
+                code: K::Enter,
+                mods: M::ALT,
+            }),
             backspace: Some(Binding {
                 code: K::Backspace,
                 mods: M::NONE,
@@ -122,6 +134,10 @@ impl Default for PromptKeymap {
                 code: K::Esc,
                 mods: M::NONE,
             }),
+            complete: Some(Binding {
+                code: K::Tab,
+                mods: M::NONE,
+            }),
             allow_plain_char_insert: true,
         }
     }
@@ -131,6 +147,7 @@ impl PromptKeymap {
     pub fn map(&self, event: &KeyMessage) -> Option<ReplBufferEvent> {
         // Explicit bindings (exact key + modifiers), ordered by precedence
         if let Some(ev) = [
+            (self.newline.as_ref(), ReplBufferEvent::Insert('\n')),
             (self.submit.as_ref(), ReplBufferEvent::Submit),
             (self.backspace.as_ref(), ReplBufferEvent::Backspace),
             (self.left.as_ref(), ReplBufferEvent::MoveLeft),
@@ -139,6 +156,7 @@ impl PromptKeymap {
             (self.end.as_ref(), ReplBufferEvent::JumpToEnd),
             (self.delete.as_ref(), ReplBufferEvent::Delete),
             (self.clear.as_ref(), ReplBufferEvent::Clear),
+            (self.complete.as_ref(), ReplBufferEvent::Complete),
         ]
         .into_iter()
         .find_map(|(b, out)| b.and_then(|b| b.matches(event).then_some(out)))
@@ -159,6 +177,7 @@ impl PromptKeymap {
     pub fn none() -> Self {
         Self {
             submit: None,
+            newline: None,
             backspace: None,
             left: None,
             right: None,
@@ -166,6 +185,7 @@ impl PromptKeymap {
             end: None,
             delete: None,
             clear: None,
+            complete: None,
             allow_plain_char_insert: false,
         }
     }
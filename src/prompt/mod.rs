@@ -1,3 +1,4 @@
+pub mod completion;
 pub mod input;
 pub mod keymap;
 pub mod renderer;
@@ -36,6 +37,12 @@ impl Plugin for PromptPlugin {
                     .clone()
                     .unwrap_or_else(|| "> ".to_string()),
             ),
+            continuation_symbol: Some(
+                self.config
+                    .continuation_symbol
+                    .clone()
+                    .unwrap_or_else(|| ". ".to_string()),
+            ),
             buffer: String::new(),
         });
         app.insert_resource(self.config.clone());
@@ -53,18 +60,23 @@ impl Plugin for PromptPlugin {
 #[derive(Resource, Default, Clone)]
 pub struct ReplPrompt {
     pub symbol: Option<String>,
+    /// Symbol shown in front of continuation lines of multi-line input.
+    pub continuation_symbol: Option<String>,
     pub buffer: String,
 }
 
 #[derive(Resource, Clone)]
 pub struct ReplPromptConfig {
     pub symbol: Option<String>,
+    /// Secondary prompt symbol for continuation lines.
+    pub continuation_symbol: Option<String>,
 }
 
 impl Default for ReplPromptConfig {
     fn default() -> Self {
         Self {
             symbol: Some("> ".to_string()),
+            continuation_symbol: Some(". ".to_string()),
         }
     }
 }
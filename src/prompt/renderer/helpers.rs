@@ -54,3 +54,15 @@ pub fn cursor_position(left: Rect, prompt_width: u16, start: usize, cursor: usiz
     let x = unclamped_x.min(max_x);
     (x, left.y)
 }
+
+/// Locate the cursor within multi-line input.
+///
+/// Returns the zero-based line index and the cursor's byte offset within that
+/// line, where lines are separated by `'\n'`.
+pub fn cursor_line_col(buffer: &str, cursor: usize) -> (usize, usize) {
+    let cursor = cursor.min(buffer.len());
+    let before = &buffer[..cursor];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    (line, cursor - line_start)
+}
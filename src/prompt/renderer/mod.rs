@@ -6,7 +6,10 @@ use std::sync::Arc;
 use bevy::prelude::*;
 use bevy_ratatui::RatatuiContext;
 use ratatui::layout::Rect;
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
 
+use super::scroll::{reserved_rows, PinnedLines};
 use super::{ReplPrompt, ReplPromptConfig};
 use crate::context::ReplContext;
 use crate::repl::{Repl, ReplSet};
@@ -49,7 +52,8 @@ impl Plugin for PromptRenderPlugin {
     }
 }
 
-/// Render entrypoint: delegates to the active renderer strategy.
+/// Render entrypoint: draws any [`PinnedLines`] above the prompt and
+/// delegates the prompt itself to the active renderer strategy.
 ///
 /// Prefers `RatatuiContext` (alternate screen) when present, otherwise falls
 /// back to `ReplContext` (main screen, no alternate screen).
@@ -60,16 +64,43 @@ pub(super) fn display_prompt(
     prompt: Res<ReplPrompt>,
     visuals: Option<Res<ReplPromptConfig>>,
     active: Res<ActiveRenderer>,
+    pinned: Option<Res<PinnedLines>>,
 ) {
     let visuals = visuals.map(|v| v.clone()).unwrap_or_default();
 
+    let pinned = pinned
+        .as_deref()
+        .map(|p| p.0.as_slice())
+        .unwrap_or_default();
+
     let render = |f: &mut ratatui::Frame<'_>| {
-        let area = Rect {
+        let mut area = Rect {
             x: 0,
             y: 0,
             width: f.area().width,
             height: f.area().height,
         };
+        // Same split as the scroll region, so pinned lines fill the rows it
+        // reserved above the prompt
+        let (pinned_rows, prompt_rows) =
+            reserved_rows(area.height, repl.line_count(), pinned.len());
+        if pinned_rows > 0 && repl.enabled {
+            let prompt_top = area.height.saturating_sub(prompt_rows);
+            let pinned_area = Rect {
+                y: prompt_top - pinned_rows,
+                height: pinned_rows,
+                ..area
+            };
+            f.render_widget(
+                Paragraph::new(pinned_text(pinned, pinned_rows as usize)),
+                pinned_area,
+            );
+            area = Rect {
+                y: prompt_top,
+                height: area.height - prompt_top,
+                ..area
+            };
+        }
         let ctx = RenderCtx {
             repl: &repl,
             prompt: &prompt,
@@ -85,3 +116,18 @@ pub(super) fn display_prompt(
         let _ = term.draw(render);
     }
 }
+
+/// The pinned lines that fit in `rows`, ending with a count of the hidden
+/// ones if they don't all fit.
+fn pinned_text(lines: &[String], rows: usize) -> Vec<Line<'_>> {
+    if lines.len() <= rows {
+        return lines.iter().map(|line| Line::raw(line.as_str())).collect();
+    }
+    let shown = rows.saturating_sub(1);
+    let mut text: Vec<Line> = lines[..shown]
+        .iter()
+        .map(|line| Line::raw(line.as_str()))
+        .collect();
+    text.push(Line::raw(format!("... {} more lines", lines.len() - shown)));
+    text
+}
@@ -1,43 +1,70 @@
-use super::helpers::{bottom_bar_area, buffer_window, cursor_position};
+use super::helpers::{bottom_bar_area, buffer_window, cursor_line_col, cursor_position};
 use super::{PromptRenderer, RenderCtx};
+use ratatui::layout::Rect;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 
-/// Simple renderer: single line, no borders, no colors, no hints
+/// Simple renderer: one line per input line, no borders, no colors, no hints
 pub struct SimpleRenderer;
 impl PromptRenderer for SimpleRenderer {
     fn render(&self, f: &mut Frame<'_>, ctx: &RenderCtx) {
-        // Always one line
         if ctx.area.height == 0 {
             return;
         }
-        let area = bottom_bar_area(ctx.area, 1);
-
-        // Layout
-        let left_area = area;
-        let prompt_symbol = ctx.prompt.symbol.clone().unwrap_or_default();
-        let prompt_width = prompt_symbol.len() as u16;
-        if left_area.width <= prompt_width {
-            return;
-        }
-        let visible_width = left_area.width - prompt_width;
-
-        // Buffer windowing
         let buffer = &ctx.repl.buffer;
         let cursor = ctx.repl.cursor_pos.min(buffer.len());
-        let (visible_buf, start) = buffer_window(buffer, cursor, visible_width);
+        let lines: Vec<&str> = buffer.split('\n').collect();
+        let (cursor_line, cursor_col) = cursor_line_col(buffer, cursor);
 
-        // Render text
-        let mut spans = Vec::with_capacity(2);
-        if !prompt_symbol.is_empty() {
-            spans.push(Span::raw(prompt_symbol));
-        }
-        spans.push(Span::raw(visible_buf));
-        f.render_widget(Paragraph::new(Line::from(spans)), left_area);
+        // One row per input line, keeping the cursor line visible if the input
+        // is taller than the terminal.
+        let height = (lines.len() as u16).min(ctx.area.height);
+        let area = bottom_bar_area(ctx.area, height);
+        let first = (lines.len() - height as usize).min(cursor_line);
 
-        // Cursor position
-        let (cursor_x, cursor_y) = cursor_position(left_area, prompt_width, start, cursor);
-        f.set_cursor_position((cursor_x, cursor_y));
+        for (row, (i, text)) in lines
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(height as usize)
+            .enumerate()
+        {
+            let left_area = Rect {
+                y: area.y + row as u16,
+                height: 1,
+                ..area
+            };
+            let prompt_symbol = if i == 0 {
+                ctx.prompt.symbol.clone()
+            } else {
+                ctx.prompt.continuation_symbol.clone()
+            }
+            .unwrap_or_default();
+            let prompt_width = prompt_symbol.len() as u16;
+            if left_area.width <= prompt_width {
+                return;
+            }
+            let visible_width = left_area.width - prompt_width;
+
+            // Buffer windowing: only the cursor line scrolls horizontally
+            let line_cursor = if i == cursor_line { cursor_col } else { 0 };
+            let (visible_buf, start) = buffer_window(text, line_cursor, visible_width);
+
+            // Render text
+            let mut spans = Vec::with_capacity(2);
+            if !prompt_symbol.is_empty() {
+                spans.push(Span::raw(prompt_symbol));
+            }
+            spans.push(Span::raw(visible_buf));
+            f.render_widget(Paragraph::new(Line::from(spans)), left_area);
+
+            // Cursor position
+            if i == cursor_line {
+                let (cursor_x, cursor_y) =
+                    cursor_position(left_area, prompt_width, start, cursor_col);
+                f.set_cursor_position((cursor_x, cursor_y));
+            }
+        }
     }
 }
@@ -9,6 +9,8 @@ pub struct ScrollRegionPlugin;
 
 impl Plugin for ScrollRegionPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<PinnedLines>();
+        app.init_resource::<ActiveScrollRegion>();
         // Ensure region is set early (before any PostStartup prints)
         app.add_systems(Startup, manage_scroll_region);
         // Run once in PostStartup too, in the labeled set, to catch cases where
@@ -20,9 +22,24 @@ impl Plugin for ScrollRegionPlugin {
                 .after(ReplSet::Render)
                 .before(ReplSet::Post),),
         );
+        // Keep the region in sync with the prompt height (multi-line input)
+        // and the terminal size while the app runs.
+        app.add_systems(
+            Update,
+            manage_scroll_region
+                .in_set(ReplSet::Render)
+                .in_set(ReplSet::All)
+                .before(super::renderer::display_prompt),
+        );
     }
 }
 
+/// Lines kept on screen between the scrolling output and the prompt, redrawn
+/// in place instead of scrolling. The scroll region grows to make room for
+/// them while there is space left above the prompt.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PinnedLines(pub Vec<String>);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ScrollRegionState {
     pub enabled: bool,
@@ -30,24 +47,41 @@ pub struct ScrollRegionState {
     pub reserved_lines: u16,
 }
 
-/// Ensure the terminal scroll region reserves the bottom prompt area so that
-/// stdout/logs scroll above the REPL prompt instead of overwriting it.
-fn manage_scroll_region(repl: Res<Repl>, mut last: Local<Option<ScrollRegionState>>) {
-    let reserved_lines: u16 = if repl.enabled { 1 } else { 0 };
+/// The scroll region last written to the terminal, shared by every schedule
+/// [`manage_scroll_region`] runs in so the initial setup only happens once.
+#[derive(Resource, Debug, Default)]
+struct ActiveScrollRegion(Option<ScrollRegionState>);
 
+/// Ensure the terminal scroll region reserves the bottom prompt area so that
+/// stdout/logs scroll above the REPL prompt instead of overwriting it. The
+/// reserved area grows with the number of lines in the input buffer and the
+/// number of [`PinnedLines`].
+fn manage_scroll_region(
+    repl: Res<Repl>,
+    pinned: Res<PinnedLines>,
+    mut active: ResMut<ActiveScrollRegion>,
+) {
     // Read terminal size; if unavailable, do nothing
     let Ok((_w, h)) = terminal::size() else {
         return;
     };
 
+    let reserved_lines: u16 = if repl.enabled {
+        let (pinned_rows, prompt_rows) = reserved_rows(h, repl.line_count(), pinned.0.len());
+        pinned_rows + prompt_rows
+    } else {
+        0
+    };
+
     let desired = ScrollRegionState {
         enabled: repl.enabled,
         height: h,
         reserved_lines,
     };
-    if last.as_ref() == Some(&desired) {
+    if active.0.as_ref() == Some(&desired) {
         return; // No change
     }
+    let last = active.0;
 
     let mut out = stdout();
     let prev_reserved = last.as_ref().map(|t| t.reserved_lines).unwrap_or(0);
@@ -63,6 +97,7 @@ fn manage_scroll_region(repl: Res<Repl>, mut last: Local<Option<ScrollRegionStat
         // DECSTBM: ESC[{top};{bottom}r with 1-based coordinates
         // Reserve `reserved_lines` at the bottom => bottom = h - reserved_lines
         let bottom = h.saturating_sub(reserved_lines);
+        grow_reserved_region(&mut out, h, reserved_lines, prev_reserved);
         let _ = write!(out, "\x1B[1;{}r", bottom);
         set_scroll_region_info(h, reserved_lines);
         scroll_reserved_region_up(
@@ -75,7 +110,19 @@ fn manage_scroll_region(repl: Res<Repl>, mut last: Local<Option<ScrollRegionStat
     }
     let _ = out.flush();
 
-    *last = Some(desired);
+    active.0 = Some(desired);
+}
+
+/// Split the rows reserved below the scrolling output into pinned rows and
+/// prompt rows, returned in that order.
+///
+/// At least one scrollable line is always left above the reserved area, and
+/// the prompt takes precedence over pinned lines when space runs out.
+pub(crate) fn reserved_rows(height: u16, prompt_lines: usize, pinned_lines: usize) -> (u16, u16) {
+    let available = height.saturating_sub(1).max(1);
+    let prompt_rows = (prompt_lines.min(available as usize) as u16).max(1);
+    let pinned_rows = pinned_lines.min((available - prompt_rows) as usize) as u16;
+    (pinned_rows, prompt_rows)
 }
 
 /// Scroll the reserved region up by emitting newlines at the last scrollable line.
@@ -106,3 +153,30 @@ fn scroll_reserved_region_up(
         }
     }
 }
+
+/// Make room for a taller prompt by scrolling existing output up.
+///
+/// This must run while the previous (larger) scroll region is still active:
+/// newlines at its last line push output up, leaving blank lines at the bottom
+/// that become part of the reserved prompt area once the new region is set.
+///
+/// # Arguments
+/// * `out` - The output stream to write terminal escape codes to.
+/// * `h` - The terminal height.
+/// * `reserved_lines` - The number of lines that will be reserved.
+/// * `prev_reserved` - The number of lines currently reserved.
+fn grow_reserved_region(
+    out: &mut std::io::Stdout,
+    h: u16,
+    reserved_lines: u16,
+    prev_reserved: u16,
+) {
+    if prev_reserved == 0 || reserved_lines <= prev_reserved {
+        return;
+    }
+    // Move to the last line of the current scroll region (1-based row)
+    let _ = write!(out, "\x1B[{};1H", h.saturating_sub(prev_reserved));
+    for _ in prev_reserved..reserved_lines {
+        let _ = writeln!(out);
+    }
+}
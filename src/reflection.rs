@@ -0,0 +1,545 @@
+//! Helpers for the reflection-powered built-in commands.
+//!
+//! Values are looked up by type name in the [`AppTypeRegistry`], printed as an
+//! indented tree, and parsed from [RON](https://github.com/ron-rs/ron) using
+//! `bevy_reflect`'s serde support.
+
+use std::any::Any;
+
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::message::{Message, MessageCursor, Messages};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::prelude::*;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use bevy::reflect::{
+    std_traits::ReflectDefault, FromReflect, FromType, ReflectRef, TypePath, TypeRegistration,
+    TypeRegistry, VariantType,
+};
+use serde::de::DeserializeSeed;
+
+/// Find a registered type by its full type path (`my_game::GameConfig`) or
+/// its short path (`GameConfig`).
+pub fn find_registration<'r>(
+    registry: &'r TypeRegistry,
+    name: &str,
+) -> Result<&'r TypeRegistration, String> {
+    if let Some(registration) = registry
+        .get_with_type_path(name)
+        .or_else(|| registry.get_with_short_type_path(name))
+    {
+        return Ok(registration);
+    }
+    if registry.is_ambiguous(name) {
+        return Err(format!(
+            "'{name}' is ambiguous, use the full type path instead"
+        ));
+    }
+    Err(format!("no registered type named '{name}'"))
+}
+
+/// Find a reflected component type by name and register it with the world if
+/// it hasn't been used yet.
+pub fn find_component<'r>(
+    world: &mut World,
+    registry: &'r TypeRegistry,
+    name: &str,
+) -> Result<(ComponentId, &'r ReflectComponent), String> {
+    let registration = find_registration(registry, name)?;
+    let reflect = registration
+        .data::<ReflectComponent>()
+        .ok_or_else(|| format!("{name} is not a reflected component"))?;
+    Ok((reflect.register_component(world), reflect))
+}
+
+/// Deserialize a value of the registered type from RON, e.g. `3`,
+/// `(speed: 1.5, lives: 3)` or `Some("Bob")`.
+///
+/// Strings may be given without quotes: if `input` isn't valid RON for a
+/// `String`, the raw input is used as the value.
+pub fn parse_ron(
+    registry: &TypeRegistry,
+    registration: &TypeRegistration,
+    input: &str,
+) -> Result<Box<dyn PartialReflect>, String> {
+    let deserializer = TypedReflectDeserializer::new(registration, registry);
+    let mut ron = ron::Deserializer::from_str(input).map_err(|e| e.to_string())?;
+    match deserializer
+        .deserialize(&mut ron)
+        .and_then(|value| ron.end().map(|_| value))
+    {
+        Ok(value) => Ok(value),
+        Err(_) if registration.type_id() == std::any::TypeId::of::<String>() => {
+            Ok(Box::new(input.to_string()))
+        }
+        Err(e) => Err(format!(
+            "invalid value for {}: {}",
+            registration.type_info().type_path_table().short_path(),
+            ron.span_error(e)
+        )),
+    }
+}
+
+/// Like [`parse_ron`], but falls back to the type's [`Default`] (via
+/// `#[reflect(Default)]`) when no input is given.
+pub fn parse_ron_or_default(
+    registry: &TypeRegistry,
+    registration: &TypeRegistration,
+    input: Option<&str>,
+) -> Result<Box<dyn PartialReflect>, String> {
+    if let Some(input) = input {
+        return parse_ron(registry, registration, input);
+    }
+    let short = registration.type_info().type_path_table().short_path();
+    registration
+        .data::<ReflectDefault>()
+        .map(|default| default.default().into_partial_reflect())
+        .ok_or_else(|| format!("{short} has no reflected Default, give a value"))
+}
+
+/// Parse `input` as RON for the type `target` currently holds.
+pub fn parse_ron_for(
+    registry: &TypeRegistry,
+    target: &dyn PartialReflect,
+    input: &str,
+) -> Result<Box<dyn PartialReflect>, String> {
+    let type_info = target
+        .get_represented_type_info()
+        .ok_or_else(|| "the target's type is unknown to reflection".to_string())?;
+    let registration = registry
+        .get(type_info.type_id())
+        .ok_or_else(|| format!("{} is not registered", type_info.type_path()))?;
+    parse_ron(registry, registration, input)
+}
+
+/// Parse `input` as RON for the type `target` currently holds and apply it.
+pub fn set_from_ron(
+    registry: &TypeRegistry,
+    target: &mut dyn PartialReflect,
+    input: &str,
+) -> Result<(), String> {
+    let value = parse_ron_for(registry, target, input)?;
+    target.try_apply(value.as_ref()).map_err(|e| e.to_string())
+}
+
+/// Type data for reading and writing a [`Message`] type by name, e.g. for
+/// `watch-messages` and `send`. Add it with `#[reflect(ReplMessage)]` next to
+/// `#[derive(Message, Reflect)]`, or with
+/// `app.register_type_data::<M, ReflectReplMessage>()`.
+#[derive(Clone)]
+pub struct ReflectReplMessage {
+    cursor: fn(&World) -> Option<MessageReadCursor>,
+    read: fn(&World, &mut MessageReadCursor, &mut dyn FnMut(&dyn Reflect)),
+    write: fn(&mut World, &dyn PartialReflect) -> Result<(), String>,
+}
+
+/// Where a [`ReflectReplMessage`] reader left off.
+pub struct MessageReadCursor(Box<dyn Any + Send + Sync>);
+
+impl ReflectReplMessage {
+    /// A cursor past the messages currently stored, or `None` if the message
+    /// type was never added with `add_message`.
+    pub fn cursor(&self, world: &World) -> Option<MessageReadCursor> {
+        (self.cursor)(world)
+    }
+
+    /// Call `f` with each message written since `cursor` last read.
+    pub fn read(
+        &self,
+        world: &World,
+        cursor: &mut MessageReadCursor,
+        f: &mut dyn FnMut(&dyn Reflect),
+    ) {
+        (self.read)(world, cursor, f);
+    }
+
+    /// Write a message built from `message`, which may be a dynamic value
+    /// such as one returned by [`parse_ron`].
+    pub fn write(&self, world: &mut World, message: &dyn PartialReflect) -> Result<(), String> {
+        (self.write)(world, message)
+    }
+}
+
+impl<M: Message + FromReflect + TypePath> FromType<M> for ReflectReplMessage {
+    fn from_type() -> Self {
+        Self {
+            cursor: |world| {
+                let messages = world.get_resource::<Messages<M>>()?;
+                Some(MessageReadCursor(Box::new(messages.get_cursor_current())))
+            },
+            read: |world, cursor, f| {
+                let Some(messages) = world.get_resource::<Messages<M>>() else {
+                    return;
+                };
+                let Some(cursor) = cursor.0.downcast_mut::<MessageCursor<M>>() else {
+                    return;
+                };
+                for message in cursor.read(messages) {
+                    f(message);
+                }
+            },
+            write: |world, message| {
+                let message = M::from_reflect(message).ok_or_else(|| {
+                    format!("could not build a {} from that value", M::short_type_path())
+                })?;
+                let mut messages = world.get_resource_mut::<Messages<M>>().ok_or_else(|| {
+                    format!(
+                        "{} was never added with app.add_message",
+                        M::short_type_path()
+                    )
+                })?;
+                messages.write(message);
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Limits for [`format_tree`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreeLimits {
+    /// Nesting deeper than this is summarized instead of expanded.
+    pub max_depth: usize,
+    /// Collections print at most this many items, followed by `... N more`.
+    pub max_items: usize,
+}
+
+impl TreeLimits {
+    /// Print everything.
+    pub const NONE: Self = Self {
+        max_depth: usize::MAX,
+        max_items: usize::MAX,
+    };
+}
+
+impl Default for TreeLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 4,
+            max_items: 16,
+        }
+    }
+}
+
+/// Render a reflected value as an indented tree, one line per field, starting
+/// at one level of indentation.
+pub fn format_tree(value: &dyn PartialReflect, limits: TreeLimits) -> Vec<String> {
+    let mut lines = Vec::new();
+    write_children(&mut lines, value, 1, limits);
+    lines
+}
+
+/// Render a reflected value on one line, cut to at most `max_width` characters.
+pub fn format_inline(value: &dyn PartialReflect, max_width: usize) -> String {
+    let text = inline(value);
+    if text.chars().count() <= max_width {
+        return text;
+    }
+    let mut cut: String = text.chars().take(max_width.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+fn inline(value: &dyn PartialReflect) -> String {
+    if let Some(leaf) = summary(value) {
+        return leaf;
+    }
+    let join = |items: Vec<String>| items.join(", ");
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => format!(
+            "{} {{ {} }}",
+            short_type(value),
+            join(
+                (0..s.field_len())
+                    .filter_map(|i| Some(format!("{}: {}", s.name_at(i)?, inline(s.field_at(i)?))))
+                    .collect()
+            )
+        ),
+        ReflectRef::TupleStruct(s) => format!(
+            "{}({})",
+            short_type(value),
+            join(s.iter_fields().map(inline).collect())
+        ),
+        ReflectRef::Tuple(t) => format!("({})", join(t.iter_fields().map(inline).collect())),
+        ReflectRef::List(l) => format!("[{}]", join(l.iter().map(inline).collect())),
+        ReflectRef::Array(a) => format!("[{}]", join(a.iter().map(inline).collect())),
+        ReflectRef::Map(m) => format!(
+            "{{{}}}",
+            join(
+                m.iter()
+                    .map(|(k, v)| format!("{}: {}", inline(k), inline(v)))
+                    .collect()
+            )
+        ),
+        ReflectRef::Set(s) => format!("{{{}}}", join(s.iter().map(inline).collect())),
+        ReflectRef::Enum(e) => match e.variant_type() {
+            VariantType::Struct => format!(
+                "{} {{ {} }}",
+                e.variant_name(),
+                join(
+                    e.iter_fields()
+                        .map(|f| format!("{}: {}", f.name().unwrap_or_default(), inline(f.value())))
+                        .collect()
+                )
+            ),
+            _ => format!(
+                "{}({})",
+                e.variant_name(),
+                join(e.iter_fields().map(|f| inline(f.value())).collect())
+            ),
+        },
+        _ => format!("{value:?}"),
+    }
+}
+
+/// Write `label: value`, expanding compound values on the following lines.
+fn write_node(
+    lines: &mut Vec<String>,
+    label: &str,
+    value: &dyn PartialReflect,
+    depth: usize,
+    limits: TreeLimits,
+) {
+    let indent = "  ".repeat(depth);
+    let len = match value.reflect_ref() {
+        ReflectRef::List(l) => format!(" ({} items)", l.len()),
+        ReflectRef::Array(a) => format!(" ({} items)", a.len()),
+        ReflectRef::Map(m) => format!(" ({} entries)", m.len()),
+        ReflectRef::Set(s) => format!(" ({} items)", s.len()),
+        _ => String::new(),
+    };
+    match summary(value) {
+        Some(leaf) => lines.push(format!("{indent}{label}: {leaf}")),
+        None if depth >= limits.max_depth => {
+            lines.push(format!(
+                "{indent}{label}: {}{len} {{ .. }}",
+                short_type(value)
+            ));
+        }
+        None => {
+            lines.push(format!("{indent}{label}: {}{len}", short_type(value)));
+            write_children(lines, value, depth + 1, limits);
+        }
+    }
+}
+
+fn write_children(
+    lines: &mut Vec<String>,
+    value: &dyn PartialReflect,
+    depth: usize,
+    limits: TreeLimits,
+) {
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => {
+            for i in 0..s.field_len() {
+                if let (Some(name), Some(field)) = (s.name_at(i), s.field_at(i)) {
+                    write_node(lines, name, field, depth, limits);
+                }
+            }
+        }
+        ReflectRef::TupleStruct(s) => {
+            for (i, field) in s.iter_fields().enumerate() {
+                write_node(lines, &i.to_string(), field, depth, limits);
+            }
+        }
+        ReflectRef::Tuple(t) => {
+            for (i, field) in t.iter_fields().enumerate() {
+                write_node(lines, &i.to_string(), field, depth, limits);
+            }
+        }
+        ReflectRef::List(l) => {
+            let items = l.iter().enumerate().map(|(i, v)| (format!("[{i}]"), v));
+            write_items(lines, l.len(), items, depth, limits);
+        }
+        ReflectRef::Array(a) => {
+            let items = a.iter().enumerate().map(|(i, v)| (format!("[{i}]"), v));
+            write_items(lines, a.len(), items, depth, limits);
+        }
+        ReflectRef::Map(m) => {
+            let items = m.iter().map(|(k, v)| (format!("{k:?}"), v));
+            write_items(lines, m.len(), items, depth, limits);
+        }
+        ReflectRef::Set(s) => {
+            let items = s.iter().map(|v| ("-".to_string(), v));
+            write_items(lines, s.len(), items, depth, limits);
+        }
+        ReflectRef::Enum(e) => {
+            for (i, field) in e.iter_fields().enumerate() {
+                let label = field
+                    .name()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| i.to_string());
+                write_node(lines, &label, field.value(), depth, limits);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Write the items of a collection, truncated to `limits.max_items`.
+fn write_items<'a>(
+    lines: &mut Vec<String>,
+    total: usize,
+    items: impl Iterator<Item = (String, &'a dyn PartialReflect)>,
+    depth: usize,
+    limits: TreeLimits,
+) {
+    for (label, item) in items.take(limits.max_items) {
+        write_node(lines, &label, item, depth, limits);
+    }
+    if total > limits.max_items {
+        let indent = "  ".repeat(depth);
+        lines.push(format!("{indent}... {} more", total - limits.max_items));
+    }
+}
+
+/// A one-line rendering for values that don't need their own subtree.
+fn summary(value: &dyn PartialReflect) -> Option<String> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) if s.field_len() == 0 => Some(short_type(value)),
+        ReflectRef::TupleStruct(s) if s.field_len() == 0 => Some(short_type(value)),
+        ReflectRef::Tuple(t) if t.field_len() == 0 => Some("()".to_string()),
+        ReflectRef::List(l) if l.is_empty() => Some("[]".to_string()),
+        ReflectRef::Array(a) if a.is_empty() => Some("[]".to_string()),
+        ReflectRef::Map(m) if m.is_empty() => Some("{}".to_string()),
+        ReflectRef::Set(s) if s.is_empty() => Some("{}".to_string()),
+        ReflectRef::Enum(e) => match e.variant_type() {
+            VariantType::Unit => Some(e.variant_name().to_string()),
+            // Newtype variants such as `Some(3)` read best on one line
+            VariantType::Tuple if e.field_len() == 1 => e
+                .field_at(0)
+                .and_then(summary)
+                .map(|inner| format!("{}({inner})", e.variant_name())),
+            _ => None,
+        },
+        ReflectRef::Opaque(_) => Some(format!("{value:?}")),
+        _ => None,
+    }
+}
+
+/// The short type path of a reflected value, e.g. `Vec3` or `Option<f32>`.
+pub fn short_type(value: &dyn PartialReflect) -> String {
+    value
+        .get_represented_type_info()
+        .map(|info| info.type_path_table().short_path().to_string())
+        .unwrap_or_else(|| value.reflect_short_type_path().to_string())
+}
+
+#[cfg(test)]
+mod reflection_tests {
+    use super::*;
+
+    #[derive(Reflect, Default)]
+    struct Config {
+        difficulty: u32,
+        name: String,
+        spawn: Vec2,
+        boss: Option<u8>,
+        tags: Vec<String>,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Config>();
+        registry.register::<String>();
+        registry.register::<u32>();
+        registry
+    }
+
+    #[test]
+    fn test_format_tree() {
+        let config = Config {
+            difficulty: 2,
+            name: "easy".to_string(),
+            spawn: Vec2::new(1.0, 2.0),
+            boss: Some(3),
+            tags: vec![],
+        };
+        assert_eq!(
+            format_tree(&config, TreeLimits::default()),
+            vec![
+                "  difficulty: 2",
+                "  name: \"easy\"",
+                "  spawn: Vec2",
+                "    x: 1.0",
+                "    y: 2.0",
+                "  boss: Some(3)",
+                "  tags: []",
+            ]
+        );
+        let shallow = TreeLimits {
+            max_depth: 1,
+            ..default()
+        };
+        assert_eq!(format_tree(&config, shallow)[2], "  spawn: Vec2 { .. }");
+    }
+
+    #[test]
+    fn test_format_tree_truncates_collections() {
+        let config = Config {
+            tags: (0..5).map(|i| i.to_string()).collect(),
+            ..default()
+        };
+        let limits = TreeLimits {
+            max_items: 2,
+            ..default()
+        };
+        let lines = format_tree(&config, limits);
+        assert_eq!(
+            lines[lines.len() - 4..],
+            [
+                "  tags: Vec<String> (5 items)",
+                "    [0]: \"0\"",
+                "    [1]: \"1\"",
+                "    ... 3 more",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_inline() {
+        let config = Config {
+            boss: Some(7),
+            ..default()
+        };
+        assert_eq!(format_inline(&config.boss, 20), "Some(7)");
+        assert_eq!(format_inline(&config.tags, 20), "[]");
+        assert_eq!(
+            format_inline(&Vec2::new(1.0, 2.0), 40),
+            "Vec2 { x: 1.0, y: 2.0 }"
+        );
+        assert_eq!(format_inline(&Vec2::new(1.0, 2.0), 8), "Vec2 { …");
+    }
+
+    #[test]
+    fn test_set_from_ron() {
+        let registry = registry();
+        let mut config = Config::default();
+        let path = config.reflect_path_mut("difficulty").unwrap();
+        set_from_ron(&registry, path, "3").unwrap();
+        assert_eq!(config.difficulty, 3);
+
+        set_from_ron(&registry, config.reflect_path_mut("name").unwrap(), "Bob").unwrap();
+        assert_eq!(config.name, "Bob");
+        set_from_ron(
+            &registry,
+            config.reflect_path_mut("name").unwrap(),
+            "\"A b\"",
+        )
+        .unwrap();
+        assert_eq!(config.name, "A b");
+
+        let err = set_from_ron(
+            &registry,
+            config.reflect_path_mut("difficulty").unwrap(),
+            "x",
+        );
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_find_registration() {
+        let registry = registry();
+        assert!(find_registration(&registry, "Config").is_ok());
+        assert!(find_registration(&registry, "Nope").is_err());
+    }
+}
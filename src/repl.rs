@@ -2,6 +2,9 @@ use bevy::prelude::*;
 use bevy_ratatui::event::InputSet;
 
 use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::command::ReplAuditLog;
 
 /// A Bevy plugin that provides a Read-Eval-Print Loop (REPL) interface for interactive command input.
 ///
@@ -13,6 +16,9 @@ use std::collections::HashMap;
 ///   - Use [`ReplPlugin::enabled()`] to start enabled (default).
 ///   - Use [`ReplPlugin::disabled()`] to start disabled.
 ///   - Use [`ReplPlugin::with_enabled(bool)`] for explicit control.
+/// - `audit_log`: Append every executed command to a JSON-lines file.
+///   - Use [`ReplPlugin::with_audit_log(path)`] to enable it.
+///   - Use [`ReplPlugin::with_audit_session(name)`] to name the session.
 ///
 /// # Usage
 /// Add the plugin to your Bevy app:
@@ -22,12 +28,16 @@ use std::collections::HashMap;
 /// ```
 pub struct ReplPlugin {
     enable_on_startup: bool,
+    audit_log: Option<PathBuf>,
+    audit_session: Option<String>,
 }
 
 impl Default for ReplPlugin {
     fn default() -> Self {
         Self {
             enable_on_startup: true,
+            audit_log: None,
+            audit_session: None,
         }
     }
 }
@@ -35,24 +45,35 @@ impl Default for ReplPlugin {
 impl ReplPlugin {
     /// Create a REPL plugin that starts enabled (default).
     pub fn enabled() -> Self {
-        Self {
-            enable_on_startup: true,
-        }
+        Self::default()
     }
 
     /// Create a REPL plugin that starts disabled (no runtime toggle in v1).
     pub fn disabled() -> Self {
-        Self {
-            enable_on_startup: false,
-        }
+        Self::with_enabled(false)
     }
 
     /// Configure whether the REPL starts enabled.
     pub fn with_enabled(enabled: bool) -> Self {
         Self {
             enable_on_startup: enabled,
+            ..default()
         }
     }
+
+    /// Append every executed command to `path` as JSON lines. See
+    /// [`ReplAuditLog`] for the record format.
+    pub fn with_audit_log(mut self, path: impl Into<PathBuf>) -> Self {
+        self.audit_log = Some(path.into());
+        self
+    }
+
+    /// Name the session recorded in the audit log, e.g. the server name.
+    /// Defaults to the process ID and start time.
+    pub fn with_audit_session(mut self, session: impl Into<String>) -> Self {
+        self.audit_session = Some(session.into());
+        self
+    }
 }
 
 impl Plugin for ReplPlugin {
@@ -61,6 +82,13 @@ impl Plugin for ReplPlugin {
             enabled: self.enable_on_startup,
             ..default()
         });
+        if let Some(path) = &self.audit_log {
+            let mut audit = ReplAuditLog::new(path);
+            if let Some(session) = &self.audit_session {
+                audit = audit.with_session(session);
+            }
+            app.insert_resource(audit);
+        }
         app.add_message::<ReplSubmitEvent>();
         app.add_message::<ReplBufferEvent>();
         app.add_message::<ReplLifecycleEvent>();
@@ -146,6 +174,10 @@ impl Repl {
         self.buffer.insert(self.cursor_pos, c);
         self.cursor_pos += 1;
     }
+    /// Number of lines in the buffer, counting continuation lines.
+    pub fn line_count(&self) -> usize {
+        self.buffer.split('\n').count()
+    }
 }
 
 pub fn repl_is_enabled(repl: Res<Repl>) -> bool {
@@ -189,7 +221,11 @@ fn on_app_exit_emit_disable(
     }
 }
 
+/// An edit to the prompt input buffer, usually produced by the keymap.
+///
+/// New editing actions may be added, so matches need a wildcard arm.
 #[derive(Message, Debug, Clone)]
+#[non_exhaustive]
 pub enum ReplBufferEvent {
     Insert(char),
     Backspace,
@@ -200,6 +236,8 @@ pub enum ReplBufferEvent {
     JumpToEnd,
     Clear,
     Submit,
+    /// Complete the command name or argument in front of the cursor.
+    Complete,
 }
 
 #[derive(Message, Debug, Clone)]